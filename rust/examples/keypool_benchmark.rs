@@ -0,0 +1,68 @@
+//! Key pool benchmark for TOPAY-Z512
+//!
+//! Compares cold (synchronous) keygen latency against pooled keygen latency
+//! via `KeyPool`, to show the amortized win from precomputing keys while
+//! the application is idle.
+
+use std::time::{Duration, Instant};
+use topayz512::keypool::KeyPool;
+use topayz512::{Kem, KeyPair};
+
+fn main() {
+    println!("=== TOPAY-Z512 Key Pool Benchmark ===\n");
+
+    benchmark_keypair();
+    benchmark_kem();
+}
+
+fn benchmark_keypair() {
+    println!("1. KeyPair Generation:");
+
+    let iterations = 200;
+
+    let cold = time(iterations, KeyPair::generate);
+
+    // Give the pool a head start so it has keys ready before we measure.
+    let pool = KeyPool::new(iterations);
+    std::thread::sleep(Duration::from_millis(100));
+    let pooled = time(iterations, || KeyPair::generate_from_pool(&pool));
+
+    report("KeyPair::generate (cold)", iterations, cold);
+    report("KeyPair::generate_from_pool (warm)", iterations, pooled);
+    println!();
+}
+
+fn benchmark_kem() {
+    println!("2. KEM Key Generation:");
+
+    let iterations = 200;
+
+    let cold = time(iterations, Kem::keygen);
+
+    let pool = KeyPool::new(iterations);
+    std::thread::sleep(Duration::from_millis(100));
+    let pooled = time(iterations, || Kem::keygen_from_pool(&pool));
+
+    report("Kem::keygen (cold)", iterations, cold);
+    report("Kem::keygen_from_pool (warm)", iterations, pooled);
+    println!();
+}
+
+fn time<F, R>(iterations: usize, mut f: F) -> Duration
+where
+    F: FnMut() -> R,
+{
+    let start = Instant::now();
+    for _ in 0..iterations {
+        let _ = f();
+    }
+    start.elapsed()
+}
+
+fn report(label: &str, iterations: usize, elapsed: Duration) {
+    println!(
+        "   {label}: {:?} total, {:?} per op",
+        elapsed,
+        elapsed / iterations as u32
+    );
+}
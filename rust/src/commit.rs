@@ -0,0 +1,264 @@
+//! Hash-based commitments built on [`Hash`]
+//!
+//! Mirrors the commit/open pattern found in pairing-crypto libraries —
+//! Pedersen-style commitments over an elliptic-curve group — but without
+//! any elliptic-curve dependency: hiding comes from a uniformly random
+//! 512-bit blinding factor `r`, and binding comes from the collision
+//! resistance of the Z512 hash. `commit` produces a [`Commitment`] the
+//! committer can publish immediately, plus an [`Opening`] to reveal later;
+//! [`verify`] recomputes the commitment from a revealed opening and compares
+//! in constant time. [`commit_many`] extends this to an ordered list —
+//! stake commitments, sealed-bid values — folding each position's individual
+//! commitment with [`Hash::concat`] so a party can later selectively open
+//! one position without revealing the others.
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use crate::hash::Hash;
+use rand_core::{CryptoRng, RngCore};
+#[cfg(feature = "std")]
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Length in bytes of the random blinding factor (512 bits).
+pub const BLINDING_LENGTH: usize = 64;
+
+/// High-performance pseudo-random number generator for blinding factors
+///
+/// Mirrors the `OptimizedRng` used by [`crate::kem`] and [`crate::threshold`].
+#[cfg(feature = "std")]
+struct OptimizedRng {
+    state: [u64; 4],
+}
+
+#[cfg(feature = "std")]
+impl OptimizedRng {
+    #[inline]
+    fn new() -> Self {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_nanos() as u64;
+
+        let state = [
+            now,
+            now.wrapping_mul(0x9E3779B97F4A7C15),
+            now.wrapping_mul(0xBF58476D1CE4E5B9),
+            now.wrapping_mul(0x94D049BB133111EB),
+        ];
+
+        Self { state }
+    }
+
+    #[inline]
+    fn next_bytes(&mut self, bytes: &mut [u8]) {
+        let mut i = 0;
+        while i < bytes.len() {
+            let random_u64 = self.next_u64();
+            let to_copy = core::cmp::min(8, bytes.len() - i);
+            bytes[i..i + to_copy].copy_from_slice(&random_u64.to_le_bytes()[..to_copy]);
+            i += to_copy;
+        }
+    }
+
+    #[inline(always)]
+    fn next_u64(&mut self) -> u64 {
+        let result = self.state[1].wrapping_mul(5).rotate_left(7).wrapping_mul(9);
+        let t = self.state[1] << 17;
+
+        self.state[2] ^= self.state[0];
+        self.state[3] ^= self.state[1];
+        self.state[1] ^= self.state[2];
+        self.state[0] ^= self.state[3];
+
+        self.state[2] ^= t;
+        self.state[3] = self.state[3].rotate_left(45);
+
+        result
+    }
+}
+
+/// A published commitment to a value, hiding it until [`Opening`] is revealed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Commitment(Hash);
+
+impl Commitment {
+    /// The commitment as raw bytes.
+    #[inline(always)]
+    pub fn as_bytes(&self) -> &[u8; 64] {
+        self.0.as_bytes()
+    }
+
+    /// The commitment as raw bytes.
+    #[inline(always)]
+    pub fn to_bytes(&self) -> [u8; 64] {
+        self.0.to_bytes()
+    }
+}
+
+/// The value and blinding factor needed to open a [`Commitment`] with [`verify`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Opening {
+    /// The committed value.
+    pub value: Vec<u8>,
+    /// The random blinding factor sampled at commit time.
+    pub r: [u8; BLINDING_LENGTH],
+}
+
+/// Commit to `value` using an internally-seeded RNG for the blinding factor.
+///
+/// See [`commit_with_rng`] to supply one.
+#[cfg(feature = "std")]
+pub fn commit(value: &[u8]) -> (Commitment, Opening) {
+    let mut rng = OptimizedRng::new();
+    let mut r = [0u8; BLINDING_LENGTH];
+    rng.next_bytes(&mut r);
+    commit_with_blinding(value, r)
+}
+
+/// Commit to `value` using a caller-supplied CSPRNG for the blinding factor.
+pub fn commit_with_rng<R: RngCore + CryptoRng>(value: &[u8], rng: &mut R) -> (Commitment, Opening) {
+    let mut r = [0u8; BLINDING_LENGTH];
+    rng.fill_bytes(&mut r);
+    commit_with_blinding(value, r)
+}
+
+fn commit_with_blinding(value: &[u8], r: [u8; BLINDING_LENGTH]) -> (Commitment, Opening) {
+    let commitment = Commitment(Hash::combine(value, &r));
+    let opening = Opening {
+        value: value.to_vec(),
+        r,
+    };
+    (commitment, opening)
+}
+
+/// Recompute the commitment from `opening` and compare it against
+/// `commitment` in constant time.
+pub fn verify(commitment: &Commitment, opening: &Opening) -> bool {
+    let expected = Hash::combine(&opening.value, &opening.r);
+    crate::utils::constant_time_eq(expected.as_bytes(), commitment.0.as_bytes())
+}
+
+/// A commitment to an ordered list of values: one [`Commitment`] per
+/// position, folded together with [`Hash::concat`] into a single `root` so
+/// the whole set is bound by one hash while still letting any position be
+/// opened on its own via [`verify_many`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BatchCommitment {
+    /// The per-position commitments, in the order they were committed.
+    pub commitments: Vec<Commitment>,
+    /// [`Hash::concat`] of `commitments`, binding the whole ordered list.
+    pub root: Hash,
+}
+
+impl BatchCommitment {
+    fn from_commitments(commitments: Vec<Commitment>) -> Self {
+        let hashes: Vec<&Hash> = commitments.iter().map(|c| &c.0).collect();
+        let root = Hash::concat(&hashes);
+        Self { commitments, root }
+    }
+}
+
+/// Commit to each of `values`, in order, using an internally-seeded RNG for
+/// each position's blinding factor.
+///
+/// See [`commit_many_with_rng`] to supply one.
+#[cfg(feature = "std")]
+pub fn commit_many(values: &[&[u8]]) -> (BatchCommitment, Vec<Opening>) {
+    let mut rng = OptimizedRng::new();
+    commit_many_with(values, |out| rng.next_bytes(out))
+}
+
+/// Commit to each of `values`, in order, using a caller-supplied CSPRNG for
+/// each position's blinding factor.
+pub fn commit_many_with_rng<R: RngCore + CryptoRng>(
+    values: &[&[u8]],
+    rng: &mut R,
+) -> (BatchCommitment, Vec<Opening>) {
+    commit_many_with(values, |out| rng.fill_bytes(out))
+}
+
+fn commit_many_with(
+    values: &[&[u8]],
+    mut fill: impl FnMut(&mut [u8]),
+) -> (BatchCommitment, Vec<Opening>) {
+    let mut commitments = Vec::with_capacity(values.len());
+    let mut openings = Vec::with_capacity(values.len());
+
+    for value in values {
+        let mut r = [0u8; BLINDING_LENGTH];
+        fill(&mut r);
+        let (commitment, opening) = commit_with_blinding(value, r);
+        commitments.push(commitment);
+        openings.push(opening);
+    }
+
+    (BatchCommitment::from_commitments(commitments), openings)
+}
+
+/// Verify that `opening` is the value committed at `index` in `batch`,
+/// re-deriving `batch.root` from `batch.commitments` first so a tampered
+/// commitment list is caught even if the individual opening still checks out.
+pub fn verify_many(batch: &BatchCommitment, index: usize, opening: &Opening) -> bool {
+    let hashes: Vec<&Hash> = batch.commitments.iter().map(|c| &c.0).collect();
+    if Hash::concat(&hashes) != batch.root {
+        return false;
+    }
+
+    match batch.commitments.get(index) {
+        Some(commitment) => verify(commitment, opening),
+        None => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_commit_verify_round_trip() {
+        let (commitment, opening) = commit(b"sealed bid: 42");
+        assert!(verify(&commitment, &opening));
+    }
+
+    #[test]
+    fn test_verify_rejects_wrong_value() {
+        let (commitment, mut opening) = commit(b"sealed bid: 42");
+        opening.value = b"sealed bid: 43".to_vec();
+        assert!(!verify(&commitment, &opening));
+    }
+
+    #[test]
+    fn test_verify_rejects_wrong_blinding_factor() {
+        let (commitment, mut opening) = commit(b"sealed bid: 42");
+        opening.r[0] ^= 0x01;
+        assert!(!verify(&commitment, &opening));
+    }
+
+    #[test]
+    fn test_two_commitments_to_same_value_differ() {
+        let (first, _) = commit(b"stake: 100");
+        let (second, _) = commit(b"stake: 100");
+        assert_ne!(first, second);
+    }
+
+    #[test]
+    fn test_commit_many_verify_many_selective_opening() {
+        let values: [&[u8]; 3] = [b"validator-a", b"validator-b", b"validator-c"];
+        let (batch, openings) = commit_many(&values);
+
+        assert!(verify_many(&batch, 1, &openings[1]));
+        // Opening the wrong position against a correct opening should fail.
+        assert!(!verify_many(&batch, 0, &openings[1]));
+    }
+
+    #[test]
+    fn test_verify_many_rejects_tampered_commitment_list() {
+        let values: [&[u8]; 2] = [b"bid-a", b"bid-b"];
+        let (mut batch, openings) = commit_many(&values);
+        let (other, _) = commit(b"substituted");
+        batch.commitments[0] = other;
+
+        assert!(!verify_many(&batch, 1, &openings[1]));
+    }
+}
@@ -0,0 +1,293 @@
+//! Fuzzy message detection (FMD) for private blockchain scanning
+//!
+//! Lets a wallet outsource transaction scanning to an untrusted server
+//! without fully revealing which outputs belong to it. A [`TaggingKey`],
+//! derived from a root secret, can mark a tag as belonging to it with
+//! [`TaggingKey::generate_tag`]; a [`DetectionKey`] extracted from that same
+//! tagging key reveals only a prefix of its internal state and tests tags
+//! with [`DetectionKey::test_tag`]. A detection key built with `p` of the
+//! key's `n` slots always recognizes its own tags, but matches an unrelated
+//! tag only by chance, with false-positive rate `2^-p` — handing a server a
+//! low-precision detection key lets it flag a noisy superset of the
+//! wallet's transactions without learning which ones are genuine.
+//!
+//! # Construction
+//!
+//! A tagging key holds `n` per-slot subkeys, each derived from the root
+//! secret. [`TaggingKey::generate_tag`] picks a fresh ephemeral value and,
+//! for each slot, hashes it against that slot's subkey to produce one
+//! pseudorandom bit; the tag is the ephemeral value plus the `n` bits.
+//! Testing a tag recomputes the same per-slot hash from the (possibly
+//! truncated) subkeys a detection key holds and compares bits: using the
+//! genuine subkeys always reproduces the same bits the tag was built with,
+//! while any other subkey reproduces an independent, unrelated bit roughly
+//! half the time. Matching on `p` such bits therefore happens by chance
+//! with probability `2^-p`.
+//!
+//! Each subkey's hash state is primed once, at key-derivation time, with a
+//! [`Hasher`] that has already absorbed the subkey; generating or testing a
+//! tag then only needs to clone that primed hasher, absorb the tag's
+//! ephemeral value, and finalize — one hash per slot, not two.
+
+#[cfg(not(feature = "std"))]
+use alloc::{string::String, vec::Vec};
+
+use crate::error::{Result, TopayzError};
+use crate::hash::Hasher;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// High-performance pseudo-random number generator for ephemeral tag values
+///
+/// Mirrors the `OptimizedRng` used by [`crate::kem`] and [`crate::sign`].
+struct OptimizedRng {
+    state: [u64; 4],
+}
+
+impl OptimizedRng {
+    #[inline]
+    fn new() -> Self {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_nanos() as u64;
+
+        let state = [
+            now,
+            now.wrapping_mul(0x9E3779B97F4A7C15),
+            now.wrapping_mul(0xBF58476D1CE4E5B9),
+            now.wrapping_mul(0x94D049BB133111EB),
+        ];
+
+        Self { state }
+    }
+
+    #[inline]
+    fn next_bytes(&mut self, bytes: &mut [u8]) {
+        let mut i = 0;
+        while i < bytes.len() {
+            let random_u64 = self.next_u64();
+            let to_copy = core::cmp::min(8, bytes.len() - i);
+            bytes[i..i + to_copy].copy_from_slice(&random_u64.to_le_bytes()[..to_copy]);
+            i += to_copy;
+        }
+    }
+
+    #[inline(always)]
+    fn next_u64(&mut self) -> u64 {
+        let result = self.state[1].wrapping_mul(5).rotate_left(7).wrapping_mul(9);
+        let t = self.state[1] << 17;
+
+        self.state[2] ^= self.state[0];
+        self.state[3] ^= self.state[1];
+        self.state[1] ^= self.state[2];
+        self.state[0] ^= self.state[3];
+
+        self.state[2] ^= t;
+        self.state[3] = self.state[3].rotate_left(45);
+
+        result
+    }
+}
+
+/// A fuzzy-detection tag attached to a scanned item (e.g. a transaction
+/// output)
+///
+/// Carries the ephemeral value [`TaggingKey::generate_tag`] picked plus one
+/// pseudorandom bit per slot.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Tag {
+    ephemeral: [u8; 64],
+    bits: Vec<bool>,
+}
+
+impl Tag {
+    /// The ephemeral value this tag was generated with
+    #[inline(always)]
+    pub fn ephemeral(&self) -> &[u8; 64] {
+        &self.ephemeral
+    }
+
+    /// The number of slots (`n`) this tag carries a bit for
+    #[inline(always)]
+    pub fn slot_count(&self) -> usize {
+        self.bits.len()
+    }
+}
+
+/// The full secret behind a fuzzy-detection identity, able to generate tags
+/// for itself and delegate partial detection capability
+///
+/// Holds `n` per-slot hash states, each already primed with that slot's
+/// subkey so that generating or testing a tag only costs one more hash per
+/// slot.
+#[derive(Clone)]
+pub struct TaggingKey {
+    slot_hashers: Vec<Hasher>,
+}
+
+impl TaggingKey {
+    /// Derive an `n`-slot tagging key from a root secret
+    pub fn from_root_secret(root_secret: &[u8], n: usize) -> Self {
+        let slot_hashers = (0..n)
+            .map(|slot| {
+                let subkey =
+                    crate::hash::Hash::combine(root_secret, &(slot as u64).to_le_bytes());
+                let mut hasher = Hasher::new();
+                hasher.update(subkey.as_bytes());
+                hasher
+            })
+            .collect();
+
+        Self { slot_hashers }
+    }
+
+    /// The number of slots (`n`) this key was derived with
+    #[inline(always)]
+    pub fn slot_count(&self) -> usize {
+        self.slot_hashers.len()
+    }
+
+    /// Generate a tag that this key's own detection keys will always match
+    pub fn generate_tag(&self) -> Tag {
+        let mut rng = OptimizedRng::new();
+        let mut ephemeral = [0u8; 64];
+        rng.next_bytes(&mut ephemeral);
+
+        let bits = self
+            .slot_hashers
+            .iter()
+            .map(|hasher| slot_bit(hasher.clone(), &ephemeral))
+            .collect();
+
+        Tag { ephemeral, bits }
+    }
+
+    /// Extract a detection key revealing only the first `p` of this key's
+    /// `n` slots, giving a tunable false-positive rate of `2^-p`
+    ///
+    /// Errors if `p` exceeds the number of slots this key holds.
+    pub fn extract_detection_key(&self, p: usize) -> Result<DetectionKey> {
+        if p > self.slot_hashers.len() {
+            return Err(TopayzError::InvalidInput(
+                "Requested precision exceeds the tagging key's slot count".to_string(),
+            ));
+        }
+
+        Ok(DetectionKey {
+            slot_hashers: self.slot_hashers[..p].to_vec(),
+        })
+    }
+}
+
+/// A delegated, reduced-precision capability that tests whether a [`Tag`]
+/// was generated by a particular [`TaggingKey`]
+///
+/// Holding only `p` of the full `n` slots, it recognizes tags from its own
+/// tagging key with certainty but matches an unrelated tag only by chance,
+/// with probability `2^-p`.
+#[derive(Clone)]
+pub struct DetectionKey {
+    slot_hashers: Vec<Hasher>,
+}
+
+impl DetectionKey {
+    /// The number of slots (`p`) this detection key was extracted with;
+    /// the key's false-positive rate is `2^-precision`
+    #[inline(always)]
+    pub fn precision(&self) -> usize {
+        self.slot_hashers.len()
+    }
+
+    /// Test whether `tag` matches this detection key's subkeys
+    ///
+    /// Returns `false` if `tag` has fewer slots than this key holds, since
+    /// it cannot have been generated by a compatible tagging key.
+    pub fn test_tag(&self, tag: &Tag) -> bool {
+        if tag.bits.len() < self.slot_hashers.len() {
+            return false;
+        }
+
+        self.slot_hashers
+            .iter()
+            .zip(tag.bits.iter())
+            .all(|(hasher, &bit)| slot_bit(hasher.clone(), &tag.ephemeral) == bit)
+    }
+}
+
+/// Derive one pseudorandom bit for a slot from its primed hasher and a tag's
+/// ephemeral value
+#[inline]
+fn slot_bit(mut hasher: Hasher, ephemeral: &[u8; 64]) -> bool {
+    hasher.update(ephemeral);
+    hasher.finalize().as_bytes()[0] & 1 == 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_owner_always_detects_own_tags() {
+        let key = TaggingKey::from_root_secret(b"wallet-root-secret", 32);
+        let detection_key = key.extract_detection_key(32).unwrap();
+
+        for _ in 0..20 {
+            let tag = key.generate_tag();
+            assert!(detection_key.test_tag(&tag));
+        }
+    }
+
+    #[test]
+    fn test_unrelated_key_rarely_matches_full_precision() {
+        let key = TaggingKey::from_root_secret(b"wallet-root-secret", 32);
+        let other_key = TaggingKey::from_root_secret(b"a-different-root-secret", 32);
+        let other_detection_key = other_key.extract_detection_key(32).unwrap();
+
+        let mut matches = 0;
+        for _ in 0..64 {
+            let tag = key.generate_tag();
+            if other_detection_key.test_tag(&tag) {
+                matches += 1;
+            }
+        }
+
+        assert_eq!(matches, 0);
+    }
+
+    #[test]
+    fn test_reduced_precision_increases_false_positive_rate() {
+        let key = TaggingKey::from_root_secret(b"wallet-root-secret", 16);
+        let other_key = TaggingKey::from_root_secret(b"a-different-root-secret", 16);
+        let low_precision = other_key.extract_detection_key(2).unwrap();
+
+        let mut matches = 0;
+        const TRIALS: u32 = 2000;
+        for _ in 0..TRIALS {
+            let tag = key.generate_tag();
+            if low_precision.test_tag(&tag) {
+                matches += 1;
+            }
+        }
+
+        // Expected false-positive rate is 2^-2 == 25%; allow generous slack
+        // since this asserts on a pseudorandom process.
+        let rate = f64::from(matches) / f64::from(TRIALS);
+        assert!(rate > 0.1 && rate < 0.4, "unexpected false-positive rate: {rate}");
+    }
+
+    #[test]
+    fn test_extract_detection_key_rejects_precision_above_slot_count() {
+        let key = TaggingKey::from_root_secret(b"wallet-root-secret", 8);
+        assert!(key.extract_detection_key(9).is_err());
+    }
+
+    #[test]
+    fn test_detection_key_rejects_incompatible_tag() {
+        let short_key = TaggingKey::from_root_secret(b"wallet-root-secret", 4);
+        let long_key = TaggingKey::from_root_secret(b"wallet-root-secret", 8);
+        let detection_key = long_key.extract_detection_key(8).unwrap();
+
+        let short_tag = short_key.generate_tag();
+        assert!(!detection_key.test_tag(&short_tag));
+    }
+}
@@ -15,6 +15,13 @@ use crate::{
 #[cfg(test)]
 use crate::kem::Kem;
 
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+use lz4_flex::block::{compress as lz4_compress, decompress as lz4_decompress};
+
+pub mod transport;
+
 /// Optimized fragment size for better cache performance and parallel processing
 pub const FRAGMENT_SIZE: usize = 512; // Increased for better throughput
 
@@ -24,6 +31,17 @@ pub const MAX_FRAGMENTS: usize = 128; // Increased limit
 /// Minimum data size to consider fragmentation (avoid overhead for small data)
 pub const MIN_FRAGMENT_THRESHOLD: usize = FRAGMENT_SIZE * 2;
 
+/// Role of a fragment within an erasure-coded set produced by
+/// `FragmentEngine::fragment_data_with_parity`. Plain (non-coded) fragments
+/// are always tagged `Data`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FragmentKind {
+    /// One of the original `k` data fragments
+    Data,
+    /// One of the `m` parity fragments computed over GF(2^8)
+    Parity,
+}
+
 /// A fragment of data for parallel processing with optimized layout
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Fragment {
@@ -35,6 +53,86 @@ pub struct Fragment {
     pub data: Vec<u8>,
     /// Fragment hash for integrity (computed lazily when needed)
     pub hash: Hash,
+    /// Whether this is an original data fragment or a parity fragment
+    pub kind: FragmentKind,
+    /// Whether `data` holds an LZ4-compressed payload rather than raw bytes
+    pub compressed: bool,
+    /// Length of `data` once decompressed; equal to `data.len()` when `compressed` is false
+    pub original_len: u32,
+    /// Number of fragments required to reconstruct the payload (the erasure-coding
+    /// threshold); equal to `total` for fragment sets with no redundancy
+    pub k: u32,
+    /// Total length of the original, unchunked payload, for stripping the
+    /// trailing zero padding erasure-coded row fragments are padded to
+    pub payload_len: u64,
+}
+
+/// Compression codec applied to a fragment's data before hashing, selected
+/// via `FragmentEngine::fragment_data_compressed`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Codec {
+    /// Store fragment data uncompressed
+    None,
+    /// Compress fragment data with LZ4 before hashing and transmission
+    Lz4,
+}
+
+/// Reed–Solomon coding parameters for an erasure-coded fragment set, needed
+/// to drive `FragmentEngine::reconstruct_with_recovery` and to strip the
+/// trailing zero padding added to make all data fragments the same length.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CodingParams {
+    /// Number of original data fragments (`k`)
+    pub data_count: u32,
+    /// Number of parity fragments (`m`)
+    pub parity_count: u32,
+    /// Length of the original, unpadded input in bytes
+    pub original_len: u64,
+}
+
+/// One party's share of a secret split by `FragmentEngine::split_secret`
+///
+/// Holds the Shamir x-coordinate (`index`, never `0`), the byte-wise
+/// polynomial evaluations at that coordinate, and an integrity hash over
+/// those bytes — mirroring how [`Fragment`] carries a `hash` alongside its
+/// `data` so corruption is caught before it reaches reconstruction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SecretShare {
+    index: u8,
+    bytes: Vec<u8>,
+    hash: Hash,
+}
+
+impl SecretShare {
+    fn new(index: u8, bytes: Vec<u8>) -> Self {
+        let hash = Hash::new(&bytes);
+        Self { index, bytes, hash }
+    }
+
+    /// The Shamir x-coordinate this share was evaluated at (`1..=n`)
+    #[inline(always)]
+    pub fn index(&self) -> u8 {
+        self.index
+    }
+
+    /// The raw byte-wise polynomial evaluations for this share
+    #[inline(always)]
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Recompute the hash over `as_bytes()` and compare against the one
+    /// taken at split time, the same recompute-and-compare check
+    /// [`Fragment::verify_fast`] does for fragment data
+    pub fn verify(&self) -> bool {
+        Hash::new(&self.bytes) == self.hash
+    }
+}
+
+impl Drop for SecretShare {
+    fn drop(&mut self) {
+        crate::utils::secure_zero(&mut self.bytes);
+    }
 }
 
 /// Fragmented operation result with optimized memory layout
@@ -42,8 +140,173 @@ pub struct Fragment {
 pub struct FragmentedResult {
     /// All fragments (pre-allocated for efficiency)
     pub fragments: Vec<Fragment>,
-    /// Combined result hash (computed once)
+    /// Combined result hash (computed once). This is the Merkle root over
+    /// `fragments`' hashes (see [`FragmentEngine::merkle_root`]), so it
+    /// doubles as the root to check inclusion proofs from
+    /// [`FragmentEngine::prove`] against, without recomputing it.
     pub combined_hash: Hash,
+    /// Reed–Solomon coding parameters, present only for erasure-coded sets
+    pub coding: Option<CodingParams>,
+}
+
+/// An inclusion proof that one fragment belongs under a
+/// [`FragmentEngine::merkle_root`]/`combined_hash`, produced by
+/// [`FragmentEngine::prove`] and checked with [`FragmentEngine::verify_proof`]
+/// without needing the rest of the fragment set.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MerkleProof {
+    /// Position of the proven fragment among the original fragments.
+    leaf_index: usize,
+    /// Sibling hash at each level from leaf to root, paired with a flag that
+    /// is `true` when the sibling sits to the right of the node being proven.
+    siblings: Vec<(Hash, bool)>,
+}
+
+/// Minimum chunk size for content-defined chunking (bytes)
+const CDC_MIN_SIZE: usize = 256;
+
+/// Target/normal chunk size for content-defined chunking (bytes)
+const CDC_NORMAL_SIZE: usize = (CDC_MIN_SIZE + FRAGMENT_SIZE) / 2;
+
+/// Maximum chunk size for content-defined chunking (bytes); bounded by `FRAGMENT_SIZE`
+const CDC_MAX_SIZE: usize = FRAGMENT_SIZE;
+
+/// Stricter cut mask used below the normal size (more 1-bits, fewer cut points)
+const CDC_MASK_S: u64 = 0x0000_0000_0000_01FF; // 9 bits set -> ~1/512 chance
+
+/// Looser cut mask used once past the normal size (fewer 1-bits, more cut points)
+const CDC_MASK_L: u64 = 0x0000_0000_0000_007F; // 7 bits set -> ~1/128 chance
+
+/// Deterministic 256-entry Gear hash table, generated at compile time via SplitMix64
+const GEAR: [u64; 256] = build_gear_table();
+
+const fn splitmix64(seed: u64) -> u64 {
+    let mut z = seed.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+const fn build_gear_table() -> [u64; 256] {
+    let mut table = [0u64; 256];
+    let mut i = 0;
+    while i < 256 {
+        table[i] = splitmix64(i as u64 + 1);
+        i += 1;
+    }
+    table
+}
+
+/// Reduction byte for the GF(2^8) field used by the Reed–Solomon parity
+/// layer, derived from the primitive polynomial x^8+x^4+x^3+x^2+1 (0x11D).
+const fn gf_mul_raw(mut a: u8, mut b: u8) -> u8 {
+    let mut product: u8 = 0;
+    let mut i = 0;
+    while i < 8 {
+        if b & 1 != 0 {
+            product ^= a;
+        }
+        let carry = a & 0x80;
+        a <<= 1;
+        if carry != 0 {
+            a ^= 0x1D;
+        }
+        b >>= 1;
+        i += 1;
+    }
+    product
+}
+
+const fn build_gf_exp() -> [u8; 256] {
+    let mut exp = [0u8; 256];
+    let mut value: u8 = 1;
+    let mut i = 0;
+    while i < 255 {
+        exp[i] = value;
+        value = gf_mul_raw(value, 2); // 2 is a generator of GF(2^8) under 0x11D; 3 only cycles through 51 of the 255 nonzero elements
+        i += 1;
+    }
+    exp[255] = exp[0];
+    exp
+}
+
+const fn build_gf_log() -> [u8; 256] {
+    let exp = build_gf_exp();
+    let mut log = [0u8; 256];
+    let mut i = 0;
+    while i < 255 {
+        log[exp[i] as usize] = i as u8;
+        i += 1;
+    }
+    log
+}
+
+const GF_EXP: [u8; 256] = build_gf_exp();
+const GF_LOG: [u8; 256] = build_gf_log();
+
+/// Multiply two GF(2^8) field elements via the log/antilog tables
+#[inline]
+fn gf_mul(a: u8, b: u8) -> u8 {
+    if a == 0 || b == 0 {
+        return 0;
+    }
+    let sum = GF_LOG[a as usize] as usize + GF_LOG[b as usize] as usize;
+    GF_EXP[sum % 255]
+}
+
+/// Invert a nonzero GF(2^8) field element
+#[inline]
+fn gf_inv(a: u8) -> u8 {
+    debug_assert!(a != 0, "zero is not invertible in GF(2^8)");
+    GF_EXP[(255 - GF_LOG[a as usize] as usize) % 255]
+}
+
+/// Coefficient `(i, j)` of the Cauchy generator matrix used to derive parity
+/// row `j` from data row `i`: `1 / (x_j + y_i)` with `x_j = k + j + 1` and
+/// `y_i = i + 1`, distinct non-zero GF(2^8) elements so every square
+/// submatrix of `[I_k; Cauchy]` stays invertible.
+fn cauchy_coefficient(data_count: usize, j: usize, i: usize) -> u8 {
+    let x_j = (data_count + j + 1) as u8;
+    let y_i = (i + 1) as u8;
+    gf_inv(x_j ^ y_i)
+}
+
+/// Invert a square matrix over GF(2^8) via Gauss-Jordan elimination
+fn gf_invert_matrix(matrix: &[Vec<u8>]) -> Result<Vec<Vec<u8>>> {
+    let n = matrix.len();
+    let mut aug: Vec<Vec<u8>> = matrix
+        .iter()
+        .enumerate()
+        .map(|(i, row)| {
+            let mut augmented_row = row.clone();
+            augmented_row.resize(2 * n, 0);
+            augmented_row[n + i] = 1;
+            augmented_row
+        })
+        .collect();
+
+    for col in 0..n {
+        let pivot_row = (col..n)
+            .find(|&r| aug[r][col] != 0)
+            .ok_or_else(|| TopayzError::FragmentationError("singular coding matrix; cannot recover data".to_string()))?;
+        aug.swap(col, pivot_row);
+
+        let inv = gf_inv(aug[col][col]);
+        for value in aug[col].iter_mut() {
+            *value = gf_mul(*value, inv);
+        }
+
+        for r in 0..n {
+            if r != col && aug[r][col] != 0 {
+                let factor = aug[r][col];
+                for c in 0..(2 * n) {
+                    aug[r][c] ^= gf_mul(factor, aug[col][c]);
+                }
+            }
+        }
+    }
+
+    Ok(aug.into_iter().map(|row| row[n..].to_vec()).collect())
 }
 
 /// High-performance fragmentation engine for parallel processing
@@ -73,18 +336,471 @@ impl FragmentEngine {
             // Avoid unnecessary allocation by using chunk directly
             let fragment_data = chunk.to_vec();
             let fragment_hash = Hash::new(&fragment_data);
-            
+            let fragment_len = fragment_data.len() as u32;
+
+            fragments.push(Fragment {
+                index: index as u32,
+                total: total_fragments as u32,
+                data: fragment_data,
+                hash: fragment_hash,
+                kind: FragmentKind::Data,
+                compressed: false,
+                original_len: fragment_len,
+                k: total_fragments as u32,
+                payload_len: data_len as u64,
+            });
+        }
+
+        Ok(fragments)
+    }
+
+    /// Fragment data at content-defined cut points (FastCDC-style) so that
+    /// identical byte runs produce identical fragments regardless of their
+    /// offset in the buffer, which is friendlier to deduplication than the
+    /// fixed-size `fragment_data` chunker.
+    ///
+    /// Boundaries are chosen with a 64-byte sliding Gear hash: `h` is rolled
+    /// forward one byte at a time as `h = (h << 1).wrapping_add(GEAR[byte])`,
+    /// and a cut is declared once at least `CDC_MIN_SIZE` bytes have been
+    /// consumed and `h & mask == 0`, using the stricter `CDC_MASK_S` below
+    /// `CDC_NORMAL_SIZE` and the looser `CDC_MASK_L` above it. A cut is forced
+    /// at `CDC_MAX_SIZE` regardless of the rolling hash.
+    pub fn fragment_data_cdc(data: &[u8]) -> Result<Vec<Fragment>> {
+        if data.is_empty() {
+            return Err(TopayzError::FragmentationError("Cannot fragment empty data".to_string()));
+        }
+
+        let mut boundaries = Vec::new();
+        let mut start = 0usize;
+        let mut h: u64 = 0;
+
+        for (i, &byte) in data.iter().enumerate() {
+            let chunk_len = i - start + 1;
+            h = (h << 1).wrapping_add(GEAR[byte as usize]);
+
+            let at_end = i == data.len() - 1;
+            let forced_cut = chunk_len >= CDC_MAX_SIZE;
+            let hash_cut = chunk_len >= CDC_MIN_SIZE
+                && if chunk_len < CDC_NORMAL_SIZE {
+                    h & CDC_MASK_S == 0
+                } else {
+                    h & CDC_MASK_L == 0
+                };
+
+            if at_end || forced_cut || hash_cut {
+                boundaries.push((start, i + 1));
+                start = i + 1;
+                h = 0;
+            }
+        }
+
+        let total_fragments = boundaries.len();
+        if total_fragments > MAX_FRAGMENTS {
+            return Err(TopayzError::FragmentationError(
+                format!("Data too large: {} fragments exceeds maximum of {}", total_fragments, MAX_FRAGMENTS)
+            ));
+        }
+
+        let mut fragments = Vec::with_capacity(total_fragments);
+        for (index, (chunk_start, chunk_end)) in boundaries.into_iter().enumerate() {
+            let fragment_data = data[chunk_start..chunk_end].to_vec();
+            let fragment_hash = Hash::new(&fragment_data);
+            let fragment_len = fragment_data.len() as u32;
+
+            fragments.push(Fragment {
+                index: index as u32,
+                total: total_fragments as u32,
+                data: fragment_data,
+                hash: fragment_hash,
+                kind: FragmentKind::Data,
+                compressed: false,
+                original_len: fragment_len,
+                k: total_fragments as u32,
+                payload_len: data.len() as u64,
+            });
+        }
+
+        Ok(fragments)
+    }
+
+    /// Fragment data into fixed-size chunks, transparently compressing each
+    /// chunk's bytes with `codec` before hashing so `verify_fast` checks the
+    /// on-wire (possibly compressed) bytes without ever decompressing.
+    /// Compression is skipped per-chunk (the `compressed` flag stays false)
+    /// whenever it doesn't actually shrink the chunk, e.g. incompressible or
+    /// very small data.
+    pub fn fragment_data_compressed(data: &[u8], codec: Codec) -> Result<Vec<Fragment>> {
+        if data.is_empty() {
+            return Err(TopayzError::FragmentationError("Cannot fragment empty data".to_string()));
+        }
+
+        let data_len = data.len();
+        let total_fragments = (data_len + FRAGMENT_SIZE - 1) / FRAGMENT_SIZE;
+
+        if total_fragments > MAX_FRAGMENTS {
+            return Err(TopayzError::FragmentationError(
+                format!("Data too large: {} fragments exceeds maximum of {}", total_fragments, MAX_FRAGMENTS)
+            ));
+        }
+
+        let mut fragments = Vec::with_capacity(total_fragments);
+        for (index, chunk) in data.chunks(FRAGMENT_SIZE).enumerate() {
+            let original_len = chunk.len() as u32;
+            let (fragment_data, compressed) = match codec {
+                Codec::Lz4 => {
+                    let candidate = lz4_compress(chunk);
+                    if candidate.len() < chunk.len() {
+                        (candidate, true)
+                    } else {
+                        (chunk.to_vec(), false)
+                    }
+                }
+                Codec::None => (chunk.to_vec(), false),
+            };
+            let fragment_hash = Hash::new(&fragment_data);
+
             fragments.push(Fragment {
                 index: index as u32,
                 total: total_fragments as u32,
                 data: fragment_data,
                 hash: fragment_hash,
+                kind: FragmentKind::Data,
+                compressed,
+                original_len,
+                k: total_fragments as u32,
+                payload_len: data_len as u64,
+            });
+        }
+
+        Ok(fragments)
+    }
+
+    /// Fragment data into `k` data fragments plus `parity_count` parity
+    /// fragments computed over GF(2^8) with a Cauchy generator matrix, so
+    /// that any `k` of the resulting `k + parity_count` fragments suffice to
+    /// reconstruct the original data via `reconstruct_with_recovery`. Data
+    /// fragments are zero-padded to `FRAGMENT_SIZE` so every row feeding the
+    /// generator matrix has equal length; the unpadded length is recorded in
+    /// the returned `CodingParams` for trailing-pad removal on recovery.
+    pub fn fragment_data_with_parity(data: &[u8], parity_count: usize) -> Result<FragmentedResult> {
+        if data.is_empty() {
+            return Err(TopayzError::FragmentationError("Cannot fragment empty data".to_string()));
+        }
+        if parity_count == 0 {
+            return Err(TopayzError::FragmentationError("parity_count must be at least 1".to_string()));
+        }
+
+        let original_len = data.len();
+        let data_count = (original_len + FRAGMENT_SIZE - 1) / FRAGMENT_SIZE;
+        let total_fragments = data_count + parity_count;
+
+        if total_fragments > MAX_FRAGMENTS {
+            return Err(TopayzError::FragmentationError(
+                format!("Data too large: {} fragments exceeds maximum of {}", total_fragments, MAX_FRAGMENTS)
+            ));
+        }
+        if total_fragments > 255 {
+            return Err(TopayzError::FragmentationError(
+                "data_count + parity_count exceeds GF(2^8) shard limit of 255".to_string()
+            ));
+        }
+
+        let mut data_rows: Vec<Vec<u8>> = Vec::with_capacity(data_count);
+        for chunk_index in 0..data_count {
+            let start = chunk_index * FRAGMENT_SIZE;
+            let end = core::cmp::min(start + FRAGMENT_SIZE, original_len);
+            let mut row = vec![0u8; FRAGMENT_SIZE];
+            row[..end - start].copy_from_slice(&data[start..end]);
+            data_rows.push(row);
+        }
+
+        let mut fragments = Vec::with_capacity(total_fragments);
+        for (index, row) in data_rows.iter().enumerate() {
+            fragments.push(Fragment {
+                index: index as u32,
+                total: total_fragments as u32,
+                hash: Hash::new(row),
+                data: row.clone(),
+                kind: FragmentKind::Data,
+                compressed: false,
+                original_len: row.len() as u32,
+                k: data_count as u32,
+                payload_len: original_len as u64,
+            });
+        }
+
+        for j in 0..parity_count {
+            let mut parity_row = vec![0u8; FRAGMENT_SIZE];
+            for (i, row) in data_rows.iter().enumerate() {
+                let coeff = cauchy_coefficient(data_count, j, i);
+                if coeff == 0 {
+                    continue;
+                }
+                for (byte, src) in parity_row.iter_mut().zip(row.iter()) {
+                    *byte ^= gf_mul(coeff, *src);
+                }
+            }
+
+            let parity_len = parity_row.len() as u32;
+            fragments.push(Fragment {
+                index: (data_count + j) as u32,
+                total: total_fragments as u32,
+                hash: Hash::new(&parity_row),
+                data: parity_row,
+                kind: FragmentKind::Parity,
+                compressed: false,
+                original_len: parity_len,
+                k: data_count as u32,
+                payload_len: original_len as u64,
+            });
+        }
+
+        let combined_hash = Self::merkle_root(&fragments)?;
+
+        Ok(FragmentedResult {
+            fragments,
+            combined_hash,
+            coding: Some(CodingParams {
+                data_count: data_count as u32,
+                parity_count: parity_count as u32,
+                original_len: original_len as u64,
+            }),
+        })
+    }
+
+    /// Reconstruct the original data from any `coding.data_count` of the
+    /// fragments produced by `fragment_data_with_parity`, tolerating up to
+    /// `coding.parity_count` lost or dropped fragments. The surviving
+    /// fragments' generator-matrix rows are inverted over the same GF(2^8)
+    /// field as [`fragment_data_ec`]/[`reconstruct_data_ec`] (see
+    /// [`gf_invert_matrix`]) and applied to recover the missing rows.
+    pub fn reconstruct_with_recovery(fragments: &[Fragment], coding: &CodingParams) -> Result<Vec<u8>> {
+        let k = coding.data_count as usize;
+
+        let mut unique: Vec<&Fragment> = Vec::new();
+        for fragment in fragments {
+            if !unique.iter().any(|f| f.index == fragment.index) {
+                unique.push(fragment);
+            }
+        }
+
+        if unique.len() < k {
+            return Err(TopayzError::FragmentationError(
+                format!("Insufficient fragments for recovery: have {}, need at least {}", unique.len(), k)
+            ));
+        }
+
+        unique.sort_by_key(|f| f.index);
+        let selected = &unique[..k];
+
+        let mut matrix: Vec<Vec<u8>> = Vec::with_capacity(k);
+        let mut encoded_rows: Vec<&Vec<u8>> = Vec::with_capacity(k);
+        for fragment in selected {
+            if !fragment.verify_fast() {
+                return Err(TopayzError::FragmentationError(
+                    format!("Fragment {} integrity verification failed", fragment.index)
+                ));
+            }
+
+            let idx = fragment.index as usize;
+            let row = if idx < k {
+                let mut identity_row = vec![0u8; k];
+                identity_row[idx] = 1;
+                identity_row
+            } else {
+                let j = idx - k;
+                (0..k).map(|i| cauchy_coefficient(k, j, i)).collect()
+            };
+
+            matrix.push(row);
+            encoded_rows.push(&fragment.data);
+        }
+
+        let inverse = gf_invert_matrix(&matrix)?;
+        let fragment_len = encoded_rows[0].len();
+        let mut original_rows: Vec<Vec<u8>> = vec![vec![0u8; fragment_len]; k];
+
+        for (r, original_row) in original_rows.iter_mut().enumerate() {
+            for (c, encoded_row) in encoded_rows.iter().enumerate() {
+                let coeff = inverse[r][c];
+                if coeff == 0 {
+                    continue;
+                }
+                for (byte, src) in original_row.iter_mut().zip(encoded_row.iter()) {
+                    *byte ^= gf_mul(coeff, *src);
+                }
+            }
+        }
+
+        let mut reconstructed = Vec::with_capacity(k * fragment_len);
+        for row in original_rows {
+            reconstructed.extend_from_slice(&row);
+        }
+        reconstructed.truncate(coding.original_len as usize);
+
+        Ok(reconstructed)
+    }
+
+    /// Split `data` into `k` systematic data fragments plus `n - k` Reed-Solomon
+    /// parity fragments, so that any `k` of the `n` fragments (in any
+    /// combination of data and parity) suffice to reconstruct the original
+    /// data via `reconstruct_data_ec`. Unlike `fragment_data_with_parity`, the
+    /// coding parameters (`k`, `payload_len`) are carried on each `Fragment`
+    /// itself rather than in a side-channel `CodingParams`, so the fragment
+    /// set is self-describing.
+    pub fn fragment_data_ec(data: &[u8], k: usize, n: usize) -> Result<Vec<Fragment>> {
+        if data.is_empty() {
+            return Err(TopayzError::FragmentationError("Cannot fragment empty data".to_string()));
+        }
+        if k == 0 {
+            return Err(TopayzError::FragmentationError("k must be at least 1".to_string()));
+        }
+        if n <= k {
+            return Err(TopayzError::FragmentationError("n must be greater than k".to_string()));
+        }
+        if n > MAX_FRAGMENTS {
+            return Err(TopayzError::FragmentationError(
+                format!("n={} exceeds maximum fragment count of {}", n, MAX_FRAGMENTS)
+            ));
+        }
+        if n > 255 {
+            return Err(TopayzError::FragmentationError(
+                "n exceeds GF(2^8) shard limit of 255".to_string()
+            ));
+        }
+
+        let payload_len = data.len() as u64;
+        let row_size = (data.len() + k - 1) / k;
+
+        let mut data_rows: Vec<Vec<u8>> = Vec::with_capacity(k);
+        for row_index in 0..k {
+            let start = row_index * row_size;
+            let end = core::cmp::min(start + row_size, data.len());
+            let mut row = vec![0u8; row_size];
+            if start < end {
+                row[..end - start].copy_from_slice(&data[start..end]);
+            }
+            data_rows.push(row);
+        }
+
+        let mut fragments = Vec::with_capacity(n);
+        for (index, row) in data_rows.iter().enumerate() {
+            fragments.push(Fragment {
+                index: index as u32,
+                total: n as u32,
+                hash: Hash::new(row),
+                data: row.clone(),
+                kind: FragmentKind::Data,
+                compressed: false,
+                original_len: row.len() as u32,
+                k: k as u32,
+                payload_len,
+            });
+        }
+
+        for j in 0..(n - k) {
+            let mut parity_row = vec![0u8; row_size];
+            for (i, row) in data_rows.iter().enumerate() {
+                let coeff = cauchy_coefficient(k, j, i);
+                if coeff == 0 {
+                    continue;
+                }
+                for (byte, src) in parity_row.iter_mut().zip(row.iter()) {
+                    *byte ^= gf_mul(coeff, *src);
+                }
+            }
+
+            let parity_len = parity_row.len() as u32;
+            fragments.push(Fragment {
+                index: (k + j) as u32,
+                total: n as u32,
+                hash: Hash::new(&parity_row),
+                data: parity_row,
+                kind: FragmentKind::Parity,
+                compressed: false,
+                original_len: parity_len,
+                k: k as u32,
+                payload_len,
             });
         }
 
         Ok(fragments)
     }
 
+    /// Reconstruct the original data from any `k` of the fragments produced by
+    /// `fragment_data_ec`, where `k` is read from the fragments themselves.
+    /// Entries that are `None` (never arrived) or whose fragment fails
+    /// `verify_fast` (corrupted in transit) are both treated as erasures, so
+    /// a corrupted-but-present fragment is simply skipped rather than
+    /// poisoning the reconstruction.
+    pub fn reconstruct_data_ec(fragments: &[Option<Fragment>]) -> Result<Vec<u8>> {
+        let mut unique: Vec<&Fragment> = Vec::new();
+        for fragment in fragments.iter().flatten() {
+            if !fragment.verify_fast() {
+                continue;
+            }
+            if !unique.iter().any(|f| f.index == fragment.index) {
+                unique.push(fragment);
+            }
+        }
+
+        let k = match unique.first() {
+            Some(fragment) => fragment.k as usize,
+            None => return Err(TopayzError::FragmentationError("No valid fragments provided".to_string())),
+        };
+
+        if unique.len() < k {
+            return Err(TopayzError::FragmentationError(
+                format!("Insufficient fragments for recovery: have {}, need at least {}", unique.len(), k)
+            ));
+        }
+
+        unique.sort_by_key(|f| f.index);
+        let selected = &unique[..k];
+        let payload_len = selected[0].payload_len;
+
+        let mut matrix: Vec<Vec<u8>> = Vec::with_capacity(k);
+        let mut encoded_rows: Vec<&Vec<u8>> = Vec::with_capacity(k);
+        for fragment in selected {
+            let idx = fragment.index as usize;
+            let row = if idx < k {
+                let mut identity_row = vec![0u8; k];
+                identity_row[idx] = 1;
+                identity_row
+            } else {
+                let j = idx - k;
+                (0..k).map(|i| cauchy_coefficient(k, j, i)).collect()
+            };
+
+            matrix.push(row);
+            encoded_rows.push(&fragment.data);
+        }
+
+        let inverse = gf_invert_matrix(&matrix)?;
+        let row_size = encoded_rows[0].len();
+        let mut original_rows: Vec<Vec<u8>> = vec![vec![0u8; row_size]; k];
+
+        for (r, original_row) in original_rows.iter_mut().enumerate() {
+            for (c, encoded_row) in encoded_rows.iter().enumerate() {
+                let coeff = inverse[r][c];
+                if coeff == 0 {
+                    continue;
+                }
+                for (byte, src) in original_row.iter_mut().zip(encoded_row.iter()) {
+                    *byte ^= gf_mul(coeff, *src);
+                }
+            }
+        }
+
+        let mut reconstructed = Vec::with_capacity(k * row_size);
+        for row in original_rows {
+            reconstructed.extend_from_slice(&row);
+        }
+        reconstructed.truncate(payload_len as usize);
+
+        Ok(reconstructed)
+    }
+
     /// Reconstruct data from fragments with optimized memory allocation
     pub fn reconstruct_data(fragments: &[Fragment]) -> Result<Vec<u8>> {
         if fragments.is_empty() {
@@ -115,7 +831,8 @@ impl FragmentEngine {
             fragment_map[idx] = Some(fragment);
         }
 
-        // Reconstruct in order with integrity verification
+        // Resolve missing fragments and total mismatches up front
+        let mut ordered: Vec<&Fragment> = Vec::with_capacity(total_fragments);
         for (expected_index, fragment_opt) in fragment_map.iter().enumerate() {
             let fragment = fragment_opt.ok_or_else(|| {
                 TopayzError::FragmentationError(
@@ -129,14 +846,32 @@ impl FragmentEngine {
                 ));
             }
 
-            // Fast integrity check
-            if !fragment.verify_fast() {
-                return Err(TopayzError::FragmentationError(
-                    format!("Fragment {} integrity verification failed", fragment.index)
-                ));
-            }
+            ordered.push(fragment);
+        }
+
+        // Integrity check across all fragments, driven by rayon when the
+        // `parallel` feature is enabled so large fragment sets verify
+        // concurrently instead of one at a time.
+        #[cfg(feature = "parallel")]
+        let validity: Vec<bool> = ordered.par_iter().map(|f| f.verify_fast()).collect();
+        #[cfg(not(feature = "parallel"))]
+        let validity: Vec<bool> = ordered.iter().map(|f| f.verify_fast()).collect();
 
-            reconstructed_data.extend_from_slice(&fragment.data);
+        if let Some(bad_index) = validity.iter().position(|valid| !valid) {
+            return Err(TopayzError::FragmentationError(
+                format!("Fragment {} integrity verification failed", ordered[bad_index].index)
+            ));
+        }
+
+        for fragment in ordered {
+            if fragment.compressed {
+                let decompressed = lz4_decompress(&fragment.data, fragment.original_len as usize).map_err(|e| {
+                    TopayzError::FragmentationError(format!("LZ4 decompression failed for fragment {}: {}", fragment.index, e))
+                })?;
+                reconstructed_data.extend_from_slice(&decompressed);
+            } else {
+                reconstructed_data.extend_from_slice(&fragment.data);
+            }
         }
 
         Ok(reconstructed_data)
@@ -148,16 +883,12 @@ impl FragmentEngine {
         let fragments = Self::fragment_data(public_key_bytes)?;
         
         // Optimized combined hash computation
-        let combined_hash = if fragments.len() == 1 {
-            fragments[0].hash.clone()
-        } else {
-            let fragment_hashes: Vec<&Hash> = fragments.iter().map(|f| &f.hash).collect();
-            Hash::concat(&fragment_hashes)
-        };
+        let combined_hash = Self::merkle_root(&fragments)?;
 
         Ok(FragmentedResult {
             fragments,
             combined_hash,
+            coding: None,
         })
     }
 
@@ -175,76 +906,278 @@ impl FragmentEngine {
             processed_data.extend_from_slice(&processed_fragment);
         }
 
-        Ok(processed_data)
+        Ok(processed_data)
+    }
+
+    /// Process fragmented KEM operations with true data-parallel execution via
+    /// rayon's `par_iter`, falling back to the sequential `process_fragmented_kem`
+    /// path when the `parallel` feature is disabled. Per-fragment results are
+    /// collected alongside their original index and re-sorted before
+    /// concatenation, so the output is deterministic regardless of how the
+    /// thread pool schedules work.
+    pub fn process_fragmented_kem_parallel(fragmented_result: &FragmentedResult) -> Result<Vec<u8>> {
+        #[cfg(feature = "parallel")]
+        {
+            let mut processed: Vec<(usize, Vec<u8>)> = fragmented_result
+                .fragments
+                .par_iter()
+                .enumerate()
+                .map(|(index, fragment)| Self::process_single_fragment_optimized(fragment).map(|bytes| (index, bytes)))
+                .collect::<Result<Vec<_>>>()?;
+            processed.sort_by_key(|(index, _)| *index);
+
+            let total_size: usize = processed.iter().map(|(_, bytes)| bytes.len()).sum();
+            let mut processed_data = Vec::with_capacity(total_size);
+            for (_, bytes) in processed {
+                processed_data.extend_from_slice(&bytes);
+            }
+
+            Ok(processed_data)
+        }
+
+        #[cfg(not(feature = "parallel"))]
+        {
+            Self::process_fragmented_kem(fragmented_result)
+        }
+    }
+
+    /// Configure the size of rayon's global thread pool used by the parallel
+    /// fragment operations. Defaults to the available core count when never
+    /// called; a no-op when the `parallel` feature is disabled. Must be
+    /// called before the pool is first used, since rayon's global pool can
+    /// only be built once per process.
+    pub fn configure_thread_pool(num_threads: usize) -> Result<()> {
+        #[cfg(feature = "parallel")]
+        {
+            rayon::ThreadPoolBuilder::new()
+                .num_threads(num_threads)
+                .build_global()
+                .map_err(|e| TopayzError::FragmentationError(format!("Failed to configure thread pool: {}", e)))
+        }
+
+        #[cfg(not(feature = "parallel"))]
+        {
+            let _ = num_threads;
+            Ok(())
+        }
+    }
+
+    /// Optimized single fragment processing
+    #[inline]
+    fn process_single_fragment_optimized(fragment: &Fragment) -> Result<Vec<u8>> {
+        // Optimized cryptographic processing simulation
+        let processed_hash = fragment.hash.xor(&Hash::new(&fragment.index.to_le_bytes()));
+        Ok(processed_hash.to_bytes().to_vec())
+    }
+
+    /// Fragment hash operations for optimized parallel processing
+    pub fn fragment_hash_operation(data: &[u8]) -> Result<FragmentedResult> {
+        let fragments = Self::fragment_data(data)?;
+        
+        // Optimized combined hash computation
+        let combined_hash = Self::merkle_root(&fragments)?;
+
+        Ok(FragmentedResult {
+            fragments,
+            combined_hash,
+            coding: None,
+        })
+    }
+
+    /// General data-parallel fragment-processing primitive: applies `f` to
+    /// every fragment across rayon's global thread pool, sized by
+    /// [`crate::features::optimal_thread_count`] (or whatever
+    /// `configure_thread_pool` set it to), returning results in the same
+    /// order as `fragments` regardless of how work was scheduled. Falls back
+    /// to a plain sequential loop when the `parallel` feature is disabled, or
+    /// when only one thread is available, since a pool buys nothing there.
+    pub fn parallel_process<F>(fragments: &[Fragment], f: F) -> Vec<Hash>
+    where
+        F: Fn(&Fragment) -> Hash + Sync,
+    {
+        #[cfg(feature = "parallel")]
+        {
+            let thread_count = crate::features::optimal_thread_count().max(1);
+            if thread_count > 1 {
+                return fragments.par_iter().map(|fragment| f(fragment)).collect();
+            }
+        }
+
+        fragments.iter().map(|fragment| f(fragment)).collect()
+    }
+
+    /// Parallel hash computation across fragments, built on
+    /// [`FragmentEngine::parallel_process`]: each fragment's hash is gathered
+    /// in parallel, then folded into a single root in index order via the
+    /// same pairwise Merkle combination `merkle_root` uses.
+    pub fn parallel_hash_compute(fragmented_result: &FragmentedResult) -> Result<Hash> {
+        if fragmented_result.fragments.is_empty() {
+            return Ok(Hash::new(&[]));
+        }
+
+        let mut level = Self::parallel_process(&fragmented_result.fragments, |fragment| fragment.hash.clone());
+        while level.len() > 1 {
+            level = Self::merkle_next_level(&level);
+        }
+
+        Ok(level.into_iter().next().expect("level is non-empty"))
+    }
+
+    /// Compute the binary Merkle root over a set of fragments' hashes: pair
+    /// adjacent hashes, hash each pair with `Hash::new` over the
+    /// concatenation, duplicate the last node on odd levels, and recurse to a
+    /// single root. A single fragment's own hash is its root.
+    pub fn merkle_root(fragments: &[Fragment]) -> Result<Hash> {
+        if fragments.is_empty() {
+            return Err(TopayzError::FragmentationError("No fragments provided".to_string()));
+        }
+
+        let mut level: Vec<Hash> = fragments.iter().map(|f| f.hash.clone()).collect();
+        while level.len() > 1 {
+            level = Self::merkle_next_level(&level);
+        }
+
+        Ok(level.into_iter().next().expect("level is non-empty"))
+    }
+
+    /// Build an inclusion proof for the fragment at `index`: the sibling hash
+    /// at each level from leaf to root, paired with a flag that is `true`
+    /// when the sibling sits to the right of the node being proven. Feed the
+    /// result to `verify_merkle_proof` to check a single fragment against
+    /// `merkle_root` without needing the rest of the set.
+    pub fn merkle_proof(fragments: &[Fragment], index: usize) -> Result<Vec<(Hash, bool)>> {
+        if fragments.is_empty() {
+            return Err(TopayzError::FragmentationError("No fragments provided".to_string()));
+        }
+        if index >= fragments.len() {
+            return Err(TopayzError::FragmentationError(format!("Fragment index {} out of bounds", index)));
+        }
+
+        let mut level: Vec<Hash> = fragments.iter().map(|f| f.hash.clone()).collect();
+        let mut pos = index;
+        let mut proof = Vec::new();
+
+        while level.len() > 1 {
+            let sibling_is_right = pos % 2 == 0;
+            let sibling_index = if sibling_is_right { pos + 1 } else { pos - 1 };
+            let sibling = if sibling_index < level.len() {
+                level[sibling_index].clone()
+            } else {
+                level[pos].clone() // odd level: last node is duplicated
+            };
+            proof.push((sibling, sibling_is_right));
+
+            level = Self::merkle_next_level(&level);
+            pos /= 2;
+        }
+
+        Ok(proof)
+    }
+
+    /// Verify an inclusion proof produced by `merkle_proof` against a known
+    /// Merkle root, without needing any of the other fragments.
+    pub fn verify_merkle_proof(leaf_hash: &Hash, _index: usize, proof: &[(Hash, bool)], root: &Hash) -> bool {
+        let mut current = leaf_hash.clone();
+
+        for (sibling, sibling_is_right) in proof {
+            let mut combined = Vec::with_capacity(current.as_bytes().len() * 2);
+            if *sibling_is_right {
+                combined.extend_from_slice(current.as_bytes());
+                combined.extend_from_slice(sibling.as_bytes());
+            } else {
+                combined.extend_from_slice(sibling.as_bytes());
+                combined.extend_from_slice(current.as_bytes());
+            }
+            current = Hash::new(&combined);
+        }
+
+        current == *root
     }
 
-    /// Optimized single fragment processing
-    #[inline]
-    fn process_single_fragment_optimized(fragment: &Fragment) -> Result<Vec<u8>> {
-        // Optimized cryptographic processing simulation
-        let processed_hash = fragment.hash.xor(&Hash::new(&fragment.index.to_le_bytes()));
-        Ok(processed_hash.to_bytes().to_vec())
+    /// Build an inclusion proof for the fragment at `index`, bundled with the
+    /// index itself so a verifier doesn't have to track it separately. Thin
+    /// typed wrapper over [`FragmentEngine::merkle_proof`]; see
+    /// [`FragmentEngine::verify_proof`] to check the result.
+    pub fn prove(fragments: &[Fragment], index: usize) -> Result<MerkleProof> {
+        let siblings = Self::merkle_proof(fragments, index)?;
+        Ok(MerkleProof { leaf_index: index, siblings })
     }
 
-    /// Fragment hash operations for optimized parallel processing
-    pub fn fragment_hash_operation(data: &[u8]) -> Result<FragmentedResult> {
-        let fragments = Self::fragment_data(data)?;
-        
-        // Optimized combined hash computation
-        let combined_hash = if fragments.len() == 1 {
-            fragments[0].hash.clone()
-        } else {
-            let fragment_hashes: Vec<&Hash> = fragments.iter().map(|f| &f.hash).collect();
-            Hash::concat(&fragment_hashes)
-        };
-
-        Ok(FragmentedResult {
-            fragments,
-            combined_hash,
-        })
+    /// Check that `fragment` is included under `root` per `proof`, without
+    /// needing any of the other fragments. Thin typed wrapper over
+    /// [`FragmentEngine::verify_merkle_proof`].
+    pub fn verify_proof(root: &Hash, fragment: &Fragment, proof: &MerkleProof) -> bool {
+        Self::verify_merkle_proof(&fragment.hash, proof.leaf_index, &proof.siblings, root)
     }
 
-    /// Optimized parallel hash computation across fragments
-    pub fn parallel_hash_compute(fragmented_result: &FragmentedResult) -> Result<Hash> {
-        if fragmented_result.fragments.is_empty() {
-            return Ok(Hash::new(&[]));
-        }
+    /// Combine one Merkle level's hashes pairwise into the next level up
+    fn merkle_next_level(level: &[Hash]) -> Vec<Hash> {
+        let mut next_level = Vec::with_capacity((level.len() + 1) / 2);
+        let mut i = 0;
+        while i < level.len() {
+            let left = &level[i];
+            let right = if i + 1 < level.len() { &level[i + 1] } else { &level[i] };
 
-        // Pre-allocate for optimal performance
-        let total_hash_size = fragmented_result.fragments.len() * 64; // Hash size
-        let mut combined_data = Vec::with_capacity(total_hash_size);
-        
-        // Efficiently combine fragment hashes
-        for fragment in &fragmented_result.fragments {
-            combined_data.extend_from_slice(fragment.hash.as_bytes());
+            let mut combined = Vec::with_capacity(left.as_bytes().len() * 2);
+            combined.extend_from_slice(left.as_bytes());
+            combined.extend_from_slice(right.as_bytes());
+            next_level.push(Hash::new(&combined));
+
+            i += 2;
         }
+        next_level
+    }
 
-        Ok(Hash::new(&combined_data))
+    /// Thread count to assume for parallel fragment processing, derived from
+    /// the measured `CapabilityProfile` rather than just the raw core count:
+    /// a weak (e.g. mobile) device gets half its cores credited, since it's
+    /// more likely to be sharing them with other work.
+    fn capability_thread_count(profile: &crate::features::CapabilityProfile) -> usize {
+        let cores = crate::features::optimal_thread_count().max(1);
+        if profile.composite_score < 0.5 {
+            core::cmp::max(1, cores / 2)
+        } else {
+            core::cmp::min(cores, 6)
+        }
     }
 
-    /// Optimized mobile device latency estimation with better modeling
+    /// Mobile device latency estimation driven by the measured
+    /// `CapabilityProfile` rather than a fixed per-fragment constant, so a
+    /// slower device reports (and budgets for) more realistic latency.
     pub fn estimate_mobile_latency(data_size: usize) -> u64 {
         if data_size <= MIN_FRAGMENT_THRESHOLD {
             return 5; // Very fast for small data
         }
 
         let fragment_count = (data_size + FRAGMENT_SIZE - 1) / FRAGMENT_SIZE;
-        
-        // Improved latency model based on real mobile performance
-        let base_latency_per_fragment = 1; // 1ms per fragment (optimized)
+        let profile = crate::features::CapabilityProfile::current();
+
+        // Per-fragment latency scales inversely with the measured composite
+        // score relative to a 1ms-per-fragment baseline: a below-baseline
+        // (e.g. mobile) device pays more per fragment, a faster host never
+        // pays less than the baseline, so fragmenting is never reported as
+        // free just because a host happens to measure very fast.
+        let scale = profile.composite_score.max(0.1);
+        let base_latency_per_fragment_ms = (1.0 / scale).clamp(1.0, 5.0);
+
         let setup_overhead = 3; // 3ms setup time
-        
-        // Parallel processing with realistic core count
-        let available_cores = core::cmp::min(fragment_count, 6); // Mobile devices typically have 4-8 cores
-        let parallel_time = (fragment_count * base_latency_per_fragment + available_cores - 1) / available_cores;
-        
-        (setup_overhead + parallel_time) as u64
+        let available_cores = core::cmp::min(fragment_count, Self::capability_thread_count(&profile));
+        let parallel_time =
+            (fragment_count as f64 * base_latency_per_fragment_ms / available_cores as f64).ceil() as u64;
+
+        setup_overhead + parallel_time
     }
 
-    /// Optimized fragmentation decision with better heuristics
+    /// Fragmentation decision whose byte threshold scales with the measured
+    /// `CapabilityProfile`: a low-scoring (e.g. mobile) device fragments more
+    /// aggressively (lower threshold), a high-throughput host waits for
+    /// larger payloads before paying fragmentation overhead.
     pub fn should_fragment(data_size: usize) -> bool {
-        // More sophisticated decision based on data size and processing overhead
-        data_size >= MIN_FRAGMENT_THRESHOLD && 
+        let profile = crate::features::CapabilityProfile::current();
+        let scale = profile.composite_score.clamp(0.25, 1.9);
+        let threshold = ((MIN_FRAGMENT_THRESHOLD as f64) * scale).max(FRAGMENT_SIZE as f64) as usize;
+
+        data_size >= threshold &&
         data_size > FRAGMENT_SIZE * 3 // Only fragment if we get at least 3 fragments
     }
 
@@ -255,23 +1188,70 @@ impl FragmentEngine {
         }
 
         let fragment_count = (data_size + FRAGMENT_SIZE - 1) / FRAGMENT_SIZE;
-        let parallel_factor = core::cmp::min(fragment_count, 6) as f64;
-        
+        let profile = crate::features::CapabilityProfile::current();
+        let parallel_factor = core::cmp::min(fragment_count, Self::capability_thread_count(&profile)) as f64;
+
         // Account for overhead but show realistic improvement
         let overhead_factor = 0.85; // 15% overhead
         parallel_factor * overhead_factor
     }
+
+    /// Split an arbitrary-length `secret` (e.g. a fragmented key) into
+    /// `total` [`SecretShare`]s such that any `threshold` of them
+    /// reconstruct it via [`FragmentEngine::recover_secret`], while any
+    /// `threshold - 1` reveal nothing about it.
+    ///
+    /// Shamir secret sharing over `GF(2^8)`, generalized from
+    /// `crate::threshold`'s 64-byte `SecretKey` scheme to any secret
+    /// length; see that module for the underlying math.
+    pub fn split_secret(secret: &[u8], threshold: u8, total: u8) -> Result<Vec<SecretShare>> {
+        let shares = crate::threshold::split_secret(secret, threshold, total)?;
+        Ok(shares
+            .into_iter()
+            .map(|(index, bytes)| SecretShare::new(index, bytes))
+            .collect())
+    }
+
+    /// Reconstruct a secret from `threshold` or more distinct [`SecretShare`]s
+    /// produced by [`FragmentEngine::split_secret`]
+    ///
+    /// Errors if fewer than `threshold` distinct shares are supplied, if any
+    /// two shares have the same index, or if any share fails
+    /// [`SecretShare::verify`] — a corrupted share is rejected outright
+    /// rather than silently feeding bad bytes into interpolation.
+    pub fn recover_secret(shares: &[SecretShare], threshold: u8) -> Result<Vec<u8>> {
+        for share in shares {
+            if !share.verify() {
+                return Err(TopayzError::InvalidInput(
+                    "Secret share failed integrity verification".to_string(),
+                ));
+            }
+        }
+
+        let points: Vec<(u8, Vec<u8>)> = shares
+            .iter()
+            .map(|share| (share.index, share.bytes.clone()))
+            .collect();
+        crate::threshold::combine_secret_bytes(&points, threshold)
+    }
 }
 
 impl Fragment {
     /// Create a new fragment with optimized hash computation
     pub fn new(index: u32, total: u32, data: Vec<u8>) -> Self {
         let hash = Hash::new(&data);
+        let original_len = data.len() as u32;
+        let payload_len = data.len() as u64;
         Fragment {
             index,
             total,
             data,
             hash,
+            kind: FragmentKind::Data,
+            compressed: false,
+            original_len,
+            k: total,
+            payload_len,
         }
     }
 
@@ -295,58 +1275,176 @@ impl Fragment {
         self.data.len()
     }
 
+    /// Length of this fragment's serialized form in bytes
+    #[inline]
+    pub fn encoded_len(&self) -> usize {
+        30 + self.data.len() + 64 // 4+4+4+1+1+4+4+8 + data + hash
+    }
+
+    /// Serialize the fragment by appending to a caller-provided buffer,
+    /// without allocating a fresh `Vec` the way [`Fragment::to_bytes`] does
+    ///
+    /// Lets a network layer reuse one scratch buffer across thousands of
+    /// fragments instead of allocating one per fragment.
+    pub fn write_to(&self, buf: &mut Vec<u8>) {
+        buf.extend_from_slice(&self.index.to_le_bytes());
+        buf.extend_from_slice(&self.total.to_le_bytes());
+        buf.extend_from_slice(&(self.data.len() as u32).to_le_bytes());
+        buf.push(match self.kind {
+            FragmentKind::Data => 0,
+            FragmentKind::Parity => 1,
+        });
+        buf.push(self.compressed as u8);
+        buf.extend_from_slice(&self.original_len.to_le_bytes());
+        buf.extend_from_slice(&self.k.to_le_bytes());
+        buf.extend_from_slice(&self.payload_len.to_le_bytes());
+        buf.extend_from_slice(&self.data);
+        buf.extend_from_slice(self.hash.as_bytes());
+    }
+
+    /// Serialize the fragment into a caller-provided buffer without allocating
+    ///
+    /// Returns the number of bytes written. Errors if `out` is smaller than
+    /// [`Fragment::encoded_len`].
+    pub fn encode_into(&self, out: &mut [u8]) -> Result<usize> {
+        let len = self.encoded_len();
+        if out.len() < len {
+            return Err(TopayzError::FragmentationError("Output buffer too small".to_string()));
+        }
+
+        let data_len = self.data.len();
+        out[0..4].copy_from_slice(&self.index.to_le_bytes());
+        out[4..8].copy_from_slice(&self.total.to_le_bytes());
+        out[8..12].copy_from_slice(&(data_len as u32).to_le_bytes());
+        out[12] = match self.kind {
+            FragmentKind::Data => 0,
+            FragmentKind::Parity => 1,
+        };
+        out[13] = self.compressed as u8;
+        out[14..18].copy_from_slice(&self.original_len.to_le_bytes());
+        out[18..22].copy_from_slice(&self.k.to_le_bytes());
+        out[22..30].copy_from_slice(&self.payload_len.to_le_bytes());
+        out[30..30 + data_len].copy_from_slice(&self.data);
+        out[30 + data_len..len].copy_from_slice(self.hash.as_bytes());
+
+        Ok(len)
+    }
+
     /// Convert fragment to bytes for transmission with optimized serialization
     pub fn to_bytes(&self) -> Vec<u8> {
-        let data_len = self.data.len();
-        let total_size = 12 + data_len + 64; // 4+4+4 + data + hash
-        let mut bytes = Vec::with_capacity(total_size);
-        
-        // Optimized serialization
-        bytes.extend_from_slice(&self.index.to_le_bytes());
-        bytes.extend_from_slice(&self.total.to_le_bytes());
-        bytes.extend_from_slice(&(data_len as u32).to_le_bytes());
-        bytes.extend_from_slice(&self.data);
-        bytes.extend_from_slice(self.hash.as_bytes());
-        
+        let mut bytes = Vec::with_capacity(self.encoded_len());
+        self.write_to(&mut bytes);
         bytes
     }
 
     /// Create fragment from bytes with optimized deserialization
     pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
-        if bytes.len() < 76 { // 12 + 64 minimum
+        Ok(FragmentView::parse(bytes)?.to_owned_fragment())
+    }
+}
+
+/// A borrowed, zero-copy view over a serialized [`Fragment`]
+///
+/// Produced by [`FragmentView::parse`], which validates the header and
+/// integrity hash but exposes `payload` as a slice into the original
+/// buffer instead of copying it into an owned `Vec`. Useful for a network
+/// layer that wants to inspect or verify a fragment without allocating.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FragmentView<'a> {
+    /// Fragment index
+    pub index: u32,
+    /// Total number of fragments
+    pub total: u32,
+    /// Whether this is an original data fragment or a parity fragment
+    pub kind: FragmentKind,
+    /// Whether `payload` holds an LZ4-compressed payload rather than raw bytes
+    pub compressed: bool,
+    /// Length of `payload` once decompressed
+    pub original_len: u32,
+    /// Number of fragments required to reconstruct the payload
+    pub k: u32,
+    /// Total length of the original, unchunked payload
+    pub payload_len: u64,
+    /// Borrowed fragment payload, still in its on-the-wire encoding
+    pub payload: &'a [u8],
+    /// Integrity hash, already verified to match `payload` by `parse`
+    pub hash: Hash,
+}
+
+impl<'a> FragmentView<'a> {
+    /// Parse and validate a serialized fragment without copying its payload
+    pub fn parse(bytes: &'a [u8]) -> Result<Self> {
+        if bytes.len() < 94 { // 30 + 64 minimum
             return Err(TopayzError::FragmentationError("Invalid fragment bytes".to_string()));
         }
 
-        // Optimized parsing
         let index = u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
         let total = u32::from_le_bytes([bytes[4], bytes[5], bytes[6], bytes[7]]);
         let data_len = u32::from_le_bytes([bytes[8], bytes[9], bytes[10], bytes[11]]) as usize;
-        
-        if bytes.len() != 12 + data_len + 64 {
+        let kind = match bytes[12] {
+            0 => FragmentKind::Data,
+            1 => FragmentKind::Parity,
+            _ => return Err(TopayzError::FragmentationError("Invalid fragment kind byte".to_string())),
+        };
+        let compressed = match bytes[13] {
+            0 => false,
+            1 => true,
+            _ => return Err(TopayzError::FragmentationError("Invalid fragment compressed flag byte".to_string())),
+        };
+        let original_len = u32::from_le_bytes([bytes[14], bytes[15], bytes[16], bytes[17]]);
+        let k = u32::from_le_bytes([bytes[18], bytes[19], bytes[20], bytes[21]]);
+        let payload_len = u64::from_le_bytes([
+            bytes[22], bytes[23], bytes[24], bytes[25],
+            bytes[26], bytes[27], bytes[28], bytes[29],
+        ]);
+
+        if bytes.len() != 30 + data_len + 64 {
             return Err(TopayzError::FragmentationError("Fragment size mismatch".to_string()));
         }
 
-        let data = bytes[12..12 + data_len].to_vec();
-        
-        // Optimized hash reconstruction
-        let hash_bytes = &bytes[12 + data_len..12 + data_len + 64];
+        let payload = &bytes[30..30 + data_len];
+
+        let hash_bytes = &bytes[30 + data_len..30 + data_len + 64];
         let mut hash_array = [0u8; 64];
         hash_array.copy_from_slice(hash_bytes);
         let hash = Hash::from_bytes(hash_array);
 
-        let fragment = Fragment {
+        if Hash::new(payload) != hash {
+            return Err(TopayzError::FragmentationError("Fragment integrity check failed".to_string()));
+        }
+
+        Ok(FragmentView {
             index,
             total,
-            data,
+            kind,
+            compressed,
+            original_len,
+            k,
+            payload_len,
+            payload,
             hash,
-        };
+        })
+    }
 
-        // Fast integrity verification
-        if !fragment.verify_fast() {
-            return Err(TopayzError::FragmentationError("Fragment integrity check failed".to_string()));
-        }
+    /// Fragment payload size in bytes
+    #[inline(always)]
+    pub fn size(&self) -> usize {
+        self.payload.len()
+    }
 
-        Ok(fragment)
+    /// Copy this view into an owned [`Fragment`]
+    pub fn to_owned_fragment(&self) -> Fragment {
+        Fragment {
+            index: self.index,
+            total: self.total,
+            data: self.payload.to_vec(),
+            hash: self.hash.clone(),
+            kind: self.kind,
+            compressed: self.compressed,
+            original_len: self.original_len,
+            k: self.k,
+            payload_len: self.payload_len,
+        }
     }
 }
 
@@ -382,19 +1480,113 @@ mod tests {
         let fragment = Fragment::new(0, 1, vec![1, 2, 3, 4, 5]);
         let bytes = fragment.to_bytes();
         let reconstructed = Fragment::from_bytes(&bytes).unwrap();
-        
+
         assert_eq!(fragment, reconstructed);
     }
 
+    #[test]
+    fn test_write_to_reuses_scratch_buffer_across_fragments() {
+        let fragments = vec![
+            Fragment::new(0, 2, vec![1, 2, 3]),
+            Fragment::new(1, 2, vec![4, 5, 6, 7]),
+        ];
+
+        let mut scratch = Vec::new();
+        for fragment in &fragments {
+            scratch.clear();
+            fragment.write_to(&mut scratch);
+            assert_eq!(scratch, fragment.to_bytes());
+        }
+    }
+
+    #[test]
+    fn test_encode_into_matches_to_bytes() {
+        let fragment = Fragment::new(3, 5, vec![9; 100]);
+        let mut buf = vec![0u8; fragment.encoded_len()];
+
+        let written = fragment.encode_into(&mut buf).unwrap();
+        assert_eq!(written, fragment.encoded_len());
+        assert_eq!(buf, fragment.to_bytes());
+    }
+
+    #[test]
+    fn test_encode_into_rejects_buffer_too_small() {
+        let fragment = Fragment::new(0, 1, vec![1, 2, 3]);
+        let mut buf = vec![0u8; fragment.encoded_len() - 1];
+
+        assert!(fragment.encode_into(&mut buf).is_err());
+    }
+
+    #[test]
+    fn test_fragment_view_parse_borrows_payload_without_copying() {
+        let fragment = Fragment::new(2, 4, vec![7; 50]);
+        let bytes = fragment.to_bytes();
+
+        let view = FragmentView::parse(&bytes).unwrap();
+        assert_eq!(view.index, fragment.index);
+        assert_eq!(view.total, fragment.total);
+        assert_eq!(view.payload, fragment.data.as_slice());
+        assert!(core::ptr::eq(view.payload.as_ptr(), &bytes[30]));
+
+        assert_eq!(view.to_owned_fragment(), fragment);
+    }
+
+    #[test]
+    fn test_fragment_view_parse_rejects_corrupted_payload() {
+        let fragment = Fragment::new(0, 1, vec![1, 2, 3, 4, 5]);
+        let mut bytes = fragment.to_bytes();
+        bytes[30] ^= 0xFF;
+
+        assert!(FragmentView::parse(&bytes).is_err());
+    }
+
     #[test]
     fn test_fragmented_kem() {
         let (public_key, _) = Kem::keygen();
         let fragmented = FragmentEngine::fragment_kem_encapsulation(&public_key).unwrap();
-        
+
         assert!(!fragmented.fragments.is_empty());
         assert_eq!(fragmented.fragments[0].total as usize, fragmented.fragments.len());
     }
 
+    #[test]
+    fn test_process_fragmented_kem_parallel_matches_sequential() {
+        let (public_key, _) = Kem::keygen();
+        let fragmented = FragmentEngine::fragment_kem_encapsulation(&public_key).unwrap();
+
+        let sequential = FragmentEngine::process_fragmented_kem(&fragmented).unwrap();
+        let parallel = FragmentEngine::process_fragmented_kem_parallel(&fragmented).unwrap();
+
+        assert_eq!(sequential, parallel);
+    }
+
+    #[test]
+    fn test_parallel_process_preserves_fragment_order() {
+        let data = vec![5u8; 3000];
+        let fragments = FragmentEngine::fragment_data(&data).unwrap();
+
+        let results = FragmentEngine::parallel_process(&fragments, |fragment| fragment.hash.clone());
+
+        assert_eq!(results.len(), fragments.len());
+        for (result, fragment) in results.iter().zip(fragments.iter()) {
+            assert_eq!(*result, fragment.hash);
+        }
+    }
+
+    #[test]
+    fn test_parallel_hash_compute_matches_merkle_root() {
+        let data = vec![9u8; 3000];
+        let fragments = FragmentEngine::fragment_data(&data).unwrap();
+        let fragmented = FragmentedResult {
+            combined_hash: FragmentEngine::merkle_root(&fragments).unwrap(),
+            fragments,
+            coding: None,
+        };
+
+        let computed = FragmentEngine::parallel_hash_compute(&fragmented).unwrap();
+        assert_eq!(computed, fragmented.combined_hash);
+    }
+
     #[test]
     fn test_mobile_latency_estimation() {
         let latency_small = FragmentEngine::estimate_mobile_latency(100);
@@ -421,9 +1613,267 @@ mod tests {
     fn test_fragment_integrity() {
         let mut fragment = Fragment::new(0, 1, vec![1, 2, 3]);
         assert!(fragment.verify());
-        
+
         // Corrupt the data
         fragment.data[0] = 99;
         assert!(!fragment.verify());
     }
+
+    #[test]
+    fn test_fragment_data_cdc_reconstructs() {
+        let data: Vec<u8> = (0..4000u32).map(|i| (i % 251) as u8).collect();
+        let fragments = FragmentEngine::fragment_data_cdc(&data).unwrap();
+
+        assert!(fragments.len() > 1);
+        for fragment in &fragments {
+            assert!(fragment.size() >= 1 && fragment.size() <= CDC_MAX_SIZE);
+        }
+
+        let reconstructed = FragmentEngine::reconstruct_data(&fragments).unwrap();
+        assert_eq!(data, reconstructed);
+    }
+
+    #[test]
+    fn test_fragment_data_cdc_is_shift_resistant() {
+        // Content-defined chunking should be insensitive to insertions: prepending
+        // a single byte should only perturb the fragment(s) near the insertion
+        // point, not every fragment, unlike the fixed-size `fragment_data` chunker.
+        let data: Vec<u8> = (0..4000u32).map(|i| ((i * 37) % 251) as u8).collect();
+        let original_fragments = FragmentEngine::fragment_data_cdc(&data).unwrap();
+
+        let mut shifted_data = vec![0xABu8];
+        shifted_data.extend_from_slice(&data);
+        let shifted_fragments = FragmentEngine::fragment_data_cdc(&shifted_data).unwrap();
+
+        let shared = shifted_fragments
+            .iter()
+            .filter(|sf| original_fragments.iter().any(|of| of.hash == sf.hash))
+            .count();
+
+        // Most chunks should be unaffected by the single-byte prepend.
+        assert!(shared as f64 >= original_fragments.len() as f64 * 0.5);
+    }
+
+    #[test]
+    fn test_fragment_data_with_parity_roundtrip_with_no_losses() {
+        let data: Vec<u8> = (0..3000u32).map(|i| (i % 251) as u8).collect();
+        let result = FragmentEngine::fragment_data_with_parity(&data, 2).unwrap();
+        let coding = result.coding.unwrap();
+
+        assert_eq!(coding.parity_count, 2);
+        assert_eq!(
+            result.fragments.iter().filter(|f| f.kind == FragmentKind::Parity).count(),
+            2
+        );
+
+        let reconstructed = FragmentEngine::reconstruct_with_recovery(&result.fragments, &coding).unwrap();
+        assert_eq!(data, reconstructed);
+    }
+
+    #[test]
+    fn test_fragment_data_with_parity_survives_dropped_fragments() {
+        let data: Vec<u8> = (0..3000u32).map(|i| ((i * 13) % 251) as u8).collect();
+        let result = FragmentEngine::fragment_data_with_parity(&data, 2).unwrap();
+        let coding = result.coding.unwrap();
+
+        // Drop two data fragments; the two parity fragments must cover for them.
+        let surviving: Vec<Fragment> = result
+            .fragments
+            .into_iter()
+            .filter(|f| f.index != 0 && f.index != 2)
+            .collect();
+
+        let reconstructed = FragmentEngine::reconstruct_with_recovery(&surviving, &coding).unwrap();
+        assert_eq!(data, reconstructed);
+    }
+
+    #[test]
+    fn test_fragment_data_with_parity_fails_with_too_few_fragments() {
+        let data: Vec<u8> = vec![7u8; 3000];
+        let result = FragmentEngine::fragment_data_with_parity(&data, 2).unwrap();
+        let coding = result.coding.unwrap();
+
+        let too_few: Vec<Fragment> = result.fragments.into_iter().take(coding.data_count as usize - 1).collect();
+        assert!(FragmentEngine::reconstruct_with_recovery(&too_few, &coding).is_err());
+    }
+
+    #[test]
+    fn test_fragment_data_ec_roundtrip_with_no_losses() {
+        let data: Vec<u8> = (0..3000u32).map(|i| (i % 251) as u8).collect();
+        let fragments = FragmentEngine::fragment_data_ec(&data, 3, 5).unwrap();
+
+        assert_eq!(fragments.len(), 5);
+        assert_eq!(fragments.iter().filter(|f| f.kind == FragmentKind::Data).count(), 3);
+        assert_eq!(fragments.iter().filter(|f| f.kind == FragmentKind::Parity).count(), 2);
+
+        let options: Vec<Option<Fragment>> = fragments.into_iter().map(Some).collect();
+        let reconstructed = FragmentEngine::reconstruct_data_ec(&options).unwrap();
+        assert_eq!(data, reconstructed);
+    }
+
+    #[test]
+    fn test_fragment_data_ec_survives_dropped_fragments() {
+        let data: Vec<u8> = (0..3000u32).map(|i| ((i * 13) % 251) as u8).collect();
+        let fragments = FragmentEngine::fragment_data_ec(&data, 3, 5).unwrap();
+
+        // Drop two of the five fragments (up to n - k); the rest still form a valid k-set.
+        let options: Vec<Option<Fragment>> = fragments
+            .into_iter()
+            .map(|f| if f.index == 0 || f.index == 2 { None } else { Some(f) })
+            .collect();
+
+        let reconstructed = FragmentEngine::reconstruct_data_ec(&options).unwrap();
+        assert_eq!(data, reconstructed);
+    }
+
+    #[test]
+    fn test_fragment_data_ec_treats_corrupted_fragment_as_erasure() {
+        let data: Vec<u8> = (0..3000u32).map(|i| ((i * 7) % 251) as u8).collect();
+        let mut fragments = FragmentEngine::fragment_data_ec(&data, 3, 5).unwrap();
+
+        // Corrupt one data fragment's bytes without updating its hash.
+        fragments[0].data[0] ^= 0xFF;
+
+        let options: Vec<Option<Fragment>> = fragments.into_iter().map(Some).collect();
+        let reconstructed = FragmentEngine::reconstruct_data_ec(&options).unwrap();
+        assert_eq!(data, reconstructed);
+    }
+
+    #[test]
+    fn test_fragment_data_ec_fails_with_too_few_fragments() {
+        let data: Vec<u8> = vec![7u8; 3000];
+        let fragments = FragmentEngine::fragment_data_ec(&data, 3, 5).unwrap();
+
+        let options: Vec<Option<Fragment>> = fragments.into_iter().take(2).map(Some).collect();
+        assert!(FragmentEngine::reconstruct_data_ec(&options).is_err());
+    }
+
+    #[test]
+    fn test_fragment_with_kind_serialization_roundtrip() {
+        let mut fragment = Fragment::new(0, 1, vec![9, 9, 9]);
+        fragment.kind = FragmentKind::Parity;
+
+        let bytes = fragment.to_bytes();
+        let reconstructed = Fragment::from_bytes(&bytes).unwrap();
+
+        assert_eq!(fragment, reconstructed);
+    }
+
+    #[test]
+    fn test_merkle_proof_verifies_each_fragment() {
+        let data: Vec<u8> = (0..4000u32).map(|i| (i % 251) as u8).collect();
+        let fragments = FragmentEngine::fragment_data(&data).unwrap();
+        let root = FragmentEngine::merkle_root(&fragments).unwrap();
+
+        for (index, fragment) in fragments.iter().enumerate() {
+            let proof = FragmentEngine::merkle_proof(&fragments, index).unwrap();
+            assert!(FragmentEngine::verify_merkle_proof(&fragment.hash, index, &proof, &root));
+        }
+    }
+
+    #[test]
+    fn test_merkle_proof_rejects_wrong_leaf() {
+        let data: Vec<u8> = (0..4000u32).map(|i| ((i * 7) % 251) as u8).collect();
+        let fragments = FragmentEngine::fragment_data(&data).unwrap();
+        let root = FragmentEngine::merkle_root(&fragments).unwrap();
+
+        let proof = FragmentEngine::merkle_proof(&fragments, 1).unwrap();
+        let wrong_leaf = Hash::new(b"not the real fragment");
+        assert!(!FragmentEngine::verify_merkle_proof(&wrong_leaf, 1, &proof, &root));
+    }
+
+    #[test]
+    fn test_merkle_root_single_fragment_is_its_own_hash() {
+        let fragments = vec![Fragment::new(0, 1, vec![1, 2, 3])];
+        let root = FragmentEngine::merkle_root(&fragments).unwrap();
+        assert_eq!(root, fragments[0].hash);
+    }
+
+    #[test]
+    fn test_prove_verify_proof_roundtrip() {
+        let data: Vec<u8> = (0..4000u32).map(|i| (i % 251) as u8).collect();
+        let fragments = FragmentEngine::fragment_data(&data).unwrap();
+        let root = FragmentEngine::merkle_root(&fragments).unwrap();
+
+        for (index, fragment) in fragments.iter().enumerate() {
+            let proof = FragmentEngine::prove(&fragments, index).unwrap();
+            assert!(FragmentEngine::verify_proof(&root, fragment, &proof));
+        }
+    }
+
+    #[test]
+    fn test_verify_proof_rejects_wrong_fragment() {
+        let data: Vec<u8> = (0..4000u32).map(|i| ((i * 7) % 251) as u8).collect();
+        let fragments = FragmentEngine::fragment_data(&data).unwrap();
+        let root = FragmentEngine::merkle_root(&fragments).unwrap();
+
+        let proof = FragmentEngine::prove(&fragments, 1).unwrap();
+        assert!(!FragmentEngine::verify_proof(&root, &fragments[2], &proof));
+    }
+
+    #[test]
+    fn test_combined_hash_serves_as_merkle_root_for_proofs() {
+        let data = vec![7u8; 5000];
+        let fragmented = FragmentEngine::fragment_data_with_parity(&data, 2).unwrap();
+
+        let proof = FragmentEngine::prove(&fragmented.fragments, 0).unwrap();
+        assert!(FragmentEngine::verify_proof(&fragmented.combined_hash, &fragmented.fragments[0], &proof));
+    }
+
+    #[test]
+    fn test_fragment_data_compressed_roundtrip() {
+        // Highly repetitive data compresses well with LZ4.
+        let data = vec![b'A'; 4000];
+        let fragments = FragmentEngine::fragment_data_compressed(&data, Codec::Lz4).unwrap();
+
+        assert!(fragments.iter().any(|f| f.compressed));
+        for fragment in &fragments {
+            assert!(fragment.verify_fast());
+        }
+
+        let reconstructed = FragmentEngine::reconstruct_data(&fragments).unwrap();
+        assert_eq!(data, reconstructed);
+    }
+
+    #[test]
+    fn test_fragment_data_compressed_codec_none_stores_raw() {
+        // Codec::None never compresses, so every fragment should be stored raw.
+        let data: Vec<u8> = (0..4000u32).map(|i| (i % 256) as u8).collect();
+        let fragments = FragmentEngine::fragment_data_compressed(&data, Codec::None).unwrap();
+
+        assert!(fragments.iter().all(|f| !f.compressed));
+
+        let reconstructed = FragmentEngine::reconstruct_data(&fragments).unwrap();
+        assert_eq!(data, reconstructed);
+    }
+
+    #[test]
+    fn test_split_secret_recover_secret_round_trip() {
+        let secret = b"a fragmented key's worth of secret material".to_vec();
+        let shares = FragmentEngine::split_secret(&secret, 3, 5).unwrap();
+
+        assert_eq!(shares.len(), 5);
+        assert!(shares.iter().all(|share| share.verify()));
+
+        let recovered = FragmentEngine::recover_secret(&shares[1..4], 3).unwrap();
+        assert_eq!(recovered, secret);
+    }
+
+    #[test]
+    fn test_recover_secret_rejects_too_few_shares() {
+        let secret = b"top secret".to_vec();
+        let shares = FragmentEngine::split_secret(&secret, 3, 5).unwrap();
+
+        assert!(FragmentEngine::recover_secret(&shares[..2], 3).is_err());
+    }
+
+    #[test]
+    fn test_recover_secret_rejects_tampered_share() {
+        let secret = b"top secret".to_vec();
+        let mut shares = FragmentEngine::split_secret(&secret, 2, 3).unwrap();
+        shares[0].bytes[0] ^= 0x01;
+
+        assert!(!shares[0].verify());
+        assert!(FragmentEngine::recover_secret(&shares[..2], 2).is_err());
+    }
 }
\ No newline at end of file
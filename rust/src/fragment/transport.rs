@@ -0,0 +1,314 @@
+//! Reliable-UDP-style transport for `Fragment`s: assigns each `FragmentedResult`
+//! a sequence id, tracks which fragments of that sequence have arrived, and
+//! hands back the reconstructed payload only once the sequence is complete
+//! (or, for erasure-coded sets, once enough parity fragments have covered the
+//! loss). This turns the raw `to_bytes`/`from_bytes` wire format into a
+//! usable reassembly layer for the lossy links the crate targets.
+
+#[cfg(not(feature = "std"))]
+use alloc::{collections::BTreeMap, string::String, vec::Vec};
+#[cfg(feature = "std")]
+use std::collections::BTreeMap;
+
+use super::{CodingParams, Fragment, FragmentEngine, FragmentedResult};
+use crate::error::{Result, TopayzError};
+
+/// Delivery guarantee a fragment is sent under. Carried in the serialized
+/// transport header so a receiver knows how to treat loss without any other
+/// context.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeliveryClass {
+    /// Drop the fragment (and the sequence it belongs to) if it hasn't
+    /// arrived by the session timeout; never retransmitted.
+    Forgettable,
+    /// Retransmit until acked, but only for the lifetime of the session that
+    /// issued it; stale once that session/key expires.
+    KeyExpirable,
+    /// Must be retransmitted until acked regardless of session lifetime.
+    Key,
+}
+
+impl DeliveryClass {
+    fn to_byte(self) -> u8 {
+        match self {
+            DeliveryClass::Forgettable => 0,
+            DeliveryClass::KeyExpirable => 1,
+            DeliveryClass::Key => 2,
+        }
+    }
+
+    fn from_byte(byte: u8) -> Result<Self> {
+        match byte {
+            0 => Ok(DeliveryClass::Forgettable),
+            1 => Ok(DeliveryClass::KeyExpirable),
+            2 => Ok(DeliveryClass::Key),
+            _ => Err(TopayzError::FragmentationError("Invalid delivery class byte".to_string())),
+        }
+    }
+}
+
+/// One fragment plus the transport header (`seq_id`, `delivery_class`) needed
+/// to route and reassemble it on the wire.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransportFrame {
+    /// Sequence id grouping all fragments of one `FragmentedResult`
+    pub seq_id: u64,
+    /// Delivery guarantee requested for this fragment
+    pub delivery_class: DeliveryClass,
+    /// The underlying fragment
+    pub fragment: Fragment,
+}
+
+impl TransportFrame {
+    /// Serialize as `seq_id (8) || delivery_class (1) || Fragment::to_bytes()`
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let fragment_bytes = self.fragment.to_bytes();
+        let mut bytes = Vec::with_capacity(9 + fragment_bytes.len());
+        bytes.extend_from_slice(&self.seq_id.to_le_bytes());
+        bytes.push(self.delivery_class.to_byte());
+        bytes.extend_from_slice(&fragment_bytes);
+        bytes
+    }
+
+    /// Parse a frame produced by `to_bytes`
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        if bytes.len() < 9 {
+            return Err(TopayzError::FragmentationError("Invalid transport frame bytes".to_string()));
+        }
+
+        let mut seq_id_bytes = [0u8; 8];
+        seq_id_bytes.copy_from_slice(&bytes[0..8]);
+        let seq_id = u64::from_le_bytes(seq_id_bytes);
+        let delivery_class = DeliveryClass::from_byte(bytes[8])?;
+        let fragment = Fragment::from_bytes(&bytes[9..])?;
+
+        Ok(TransportFrame { seq_id, delivery_class, fragment })
+    }
+}
+
+/// Reassembly state for one in-flight sequence
+struct PendingSequence {
+    total: u32,
+    delivery_class: DeliveryClass,
+    coding: Option<CodingParams>,
+    received: BTreeMap<u32, Fragment>,
+}
+
+/// Receiver-side session that reassembles fragments delivered out of order
+/// (and possibly incompletely) over a lossy link.
+pub struct FragmentSession {
+    next_seq_id: u64,
+    pending: BTreeMap<u64, PendingSequence>,
+}
+
+impl FragmentSession {
+    /// Create an empty session
+    pub fn new() -> Self {
+        FragmentSession {
+            next_seq_id: 0,
+            pending: BTreeMap::new(),
+        }
+    }
+
+    /// Assign the next sequence id to a fragmented result and produce the
+    /// wire frames to send, each tagged with `delivery_class`. Also seeds the
+    /// session's reassembly state for `seq_id` so `missing_fragments` and
+    /// `set_coding` work correctly even before the first fragment is
+    /// received back (e.g. a sender querying its own session, or a receiver
+    /// told about `coding` out of band before any fragment arrives).
+    pub fn prepare_send(&mut self, result: &FragmentedResult, delivery_class: DeliveryClass) -> (u64, Vec<TransportFrame>) {
+        let seq_id = self.next_seq_id;
+        self.next_seq_id += 1;
+
+        let frames: Vec<TransportFrame> = result
+            .fragments
+            .iter()
+            .map(|fragment| TransportFrame {
+                seq_id,
+                delivery_class,
+                fragment: fragment.clone(),
+            })
+            .collect();
+
+        self.pending.insert(seq_id, PendingSequence {
+            total: frames.len() as u32,
+            delivery_class,
+            coding: None,
+            received: BTreeMap::new(),
+        });
+
+        (seq_id, frames)
+    }
+
+    /// Feed one received, serialized `TransportFrame` into the session.
+    /// Returns the reconstructed payload once its sequence is complete (all
+    /// fragments, or `coding.data_count` of them when erasure-coded).
+    pub fn on_receive(&mut self, bytes: &[u8]) -> Result<Option<Vec<u8>>> {
+        let frame = TransportFrame::from_bytes(bytes)?;
+
+        let pending = self.pending.entry(frame.seq_id).or_insert_with(|| PendingSequence {
+            total: frame.fragment.total,
+            delivery_class: frame.delivery_class,
+            coding: None,
+            received: BTreeMap::new(),
+        });
+
+        pending.received.insert(frame.fragment.index, frame.fragment);
+
+        let have_enough = match pending.coding {
+            Some(coding) => pending.received.len() >= coding.data_count as usize,
+            None => pending.received.len() >= pending.total as usize,
+        };
+
+        if !have_enough {
+            return Ok(None);
+        }
+
+        let fragments: Vec<Fragment> = pending.received.values().cloned().collect();
+        let reconstructed = match pending.coding {
+            Some(coding) => FragmentEngine::reconstruct_with_recovery(&fragments, &coding)?,
+            None => FragmentEngine::reconstruct_data(&fragments)?,
+        };
+
+        self.pending.remove(&frame.seq_id);
+        Ok(Some(reconstructed))
+    }
+
+    /// Tell the session that `seq_id` is erasure-coded, so it can complete
+    /// once `coding.data_count` fragments have arrived instead of all of them.
+    pub fn set_coding(&mut self, seq_id: u64, coding: CodingParams) {
+        if let Some(pending) = self.pending.get_mut(&seq_id) {
+            pending.coding = Some(coding);
+        }
+    }
+
+    /// Indices of `seq_id` not yet received, so the sender knows exactly
+    /// which fragments to resend.
+    pub fn missing_fragments(&self, seq_id: u64) -> Vec<u32> {
+        match self.pending.get(&seq_id) {
+            Some(pending) => (0..pending.total).filter(|index| !pending.received.contains_key(index)).collect(),
+            None => Vec::new(),
+        }
+    }
+
+    /// Delivery class a pending sequence was sent under, if still tracked
+    pub fn delivery_class(&self, seq_id: u64) -> Option<DeliveryClass> {
+        self.pending.get(&seq_id).map(|pending| pending.delivery_class)
+    }
+
+    /// Drop a pending sequence outright, e.g. once its `Forgettable` timeout
+    /// has elapsed or its session/key has expired
+    pub fn drop_sequence(&mut self, seq_id: u64) {
+        self.pending.remove(&seq_id);
+    }
+}
+
+impl Default for FragmentSession {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fragment::FragmentEngine;
+
+    #[test]
+    fn test_transport_frame_roundtrip() {
+        let fragment = Fragment::new(0, 2, vec![1, 2, 3, 4]);
+        let frame = TransportFrame {
+            seq_id: 42,
+            delivery_class: DeliveryClass::Key,
+            fragment,
+        };
+
+        let bytes = frame.to_bytes();
+        let reconstructed = TransportFrame::from_bytes(&bytes).unwrap();
+
+        assert_eq!(frame, reconstructed);
+    }
+
+    #[test]
+    fn test_session_reassembles_in_order_arrival() {
+        let data = vec![7u8; 2000];
+        let fragments = FragmentEngine::fragment_data(&data).unwrap();
+        let result = FragmentedResult {
+            fragments,
+            combined_hash: crate::hash::Hash::new(&[]),
+            coding: None,
+        };
+
+        let mut session = FragmentSession::new();
+        let (seq_id, frames) = session.prepare_send(&result, DeliveryClass::Key);
+        assert_eq!(session.missing_fragments(seq_id).len(), frames.len());
+
+        let mut reconstructed = None;
+        for frame in &frames {
+            reconstructed = session.on_receive(&frame.to_bytes()).unwrap();
+        }
+
+        assert_eq!(reconstructed.unwrap(), data);
+    }
+
+    #[test]
+    fn test_session_reassembles_out_of_order_arrival() {
+        let data = vec![3u8; 3000];
+        let fragments = FragmentEngine::fragment_data(&data).unwrap();
+        let result = FragmentedResult {
+            fragments,
+            combined_hash: crate::hash::Hash::new(&[]),
+            coding: None,
+        };
+
+        let mut session = FragmentSession::new();
+        let (_seq_id, mut frames) = session.prepare_send(&result, DeliveryClass::Forgettable);
+        frames.reverse();
+
+        let mut reconstructed = None;
+        for frame in &frames {
+            reconstructed = session.on_receive(&frame.to_bytes()).unwrap();
+        }
+
+        assert_eq!(reconstructed.unwrap(), data);
+    }
+
+    #[test]
+    fn test_missing_fragments_tracks_partial_arrival() {
+        let data = vec![1u8; 3000];
+        let fragments = FragmentEngine::fragment_data(&data).unwrap();
+        let result = FragmentedResult {
+            fragments,
+            combined_hash: crate::hash::Hash::new(&[]),
+            coding: None,
+        };
+
+        let mut session = FragmentSession::new();
+        let (seq_id, frames) = session.prepare_send(&result, DeliveryClass::KeyExpirable);
+
+        session.on_receive(&frames[0].to_bytes()).unwrap();
+        let missing = session.missing_fragments(seq_id);
+
+        assert_eq!(missing.len(), frames.len() - 1);
+        assert!(!missing.contains(&0));
+    }
+
+    #[test]
+    fn test_session_completes_with_parity_recovery() {
+        let data: Vec<u8> = (0..3000u32).map(|i| (i % 251) as u8).collect();
+        let fragment_result = FragmentEngine::fragment_data_with_parity(&data, 2).unwrap();
+        let coding = fragment_result.coding.unwrap();
+
+        let mut session = FragmentSession::new();
+        let (seq_id, frames) = session.prepare_send(&fragment_result, DeliveryClass::Key);
+        session.set_coding(seq_id, coding);
+
+        // Drop two data fragments; the session should still complete using parity.
+        let mut reconstructed = None;
+        for frame in frames.iter().filter(|f| f.fragment.index != 0 && f.fragment.index != 1) {
+            reconstructed = session.on_receive(&frame.to_bytes()).unwrap();
+        }
+
+        assert_eq!(reconstructed.unwrap(), data);
+    }
+}
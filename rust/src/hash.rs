@@ -1,14 +1,81 @@
 //! 512-bit cryptographic hash implementation for TOPAY-Z512
-//! 
-//! This module provides an optimized hash implementation for demonstration purposes.
-//! In production, this would use SHA3-512 or another quantum-resistant hash function.
+//!
+//! Built on a genuine Keccak-512 sponge (the same permutation family as
+//! SHA3-512): input is absorbed in [`KECCAK_RATE_BYTES`]-byte blocks under
+//! the `keccak-f[1600]` permutation and 64 bytes are squeezed out at the
+//! end. `Hash::from_bytes`/`to_hex`/`from_hex`/`xor` are unaffected by this
+//! — only the internals of [`Hash::new`], [`Hash::combine`],
+//! [`Hash::concat`] and [`Hash::hmac`] depend on the sponge.
 
 #[cfg(not(feature = "std"))]
 use alloc::{vec::Vec, string::String};
 
 use crate::{error::{TopayzError, Result}, HASH_SIZE};
-use std::collections::hash_map::DefaultHasher;
-use std::hash::{Hash as StdHash, Hasher};
+
+/// Rate of the sponge in bytes: `1600 - 2 * 512` capacity bits, i.e. a
+/// 576-bit rate, matching the SHA3-512 parameterization of Keccak.
+const KECCAK_RATE_BYTES: usize = 72;
+
+/// Block size used by [`Hash::hmac`]'s key padding; matches the sponge rate.
+const HMAC_BLOCK_SIZE: usize = KECCAK_RATE_BYTES;
+
+/// SHA3 domain-separation suffix appended to the message before the
+/// `pad10*1` padding (NIST FIPS 202, section 5.1).
+const SHA3_DOMAIN_SUFFIX: u8 = 0x06;
+
+/// Keccak-f\[1600\] round constants (ι step), one per round.
+const ROUND_CONSTANTS: [u64; 24] = [
+    0x0000000000000001, 0x0000000000008082, 0x800000000000808a, 0x8000000080008000,
+    0x000000000000808b, 0x0000000080000001, 0x8000000080008081, 0x8000000000008009,
+    0x000000000000008a, 0x0000000000000088, 0x0000000080008009, 0x000000008000000a,
+    0x000000008000808b, 0x800000000000008b, 0x8000000000008089, 0x8000000000008003,
+    0x8000000000008002, 0x8000000000000080, 0x000000000000800a, 0x800000008000000a,
+    0x8000000080008081, 0x8000000000008080, 0x0000000080000001, 0x8000000080008008,
+];
+
+/// Keccak-f\[1600\] rotation offsets (ρ step), indexed by lane `x + 5*y`.
+const ROTATION_OFFSETS: [u32; 25] = [
+    0, 1, 62, 28, 27, 36, 44, 6, 55, 20, 3, 10, 43, 25, 39, 41, 45, 15, 21, 8, 18, 2, 61, 56, 14,
+];
+
+/// Apply the 24 rounds of the Keccak-f\[1600\] permutation to a 25-lane state.
+fn keccak_f1600(state: &mut [u64; 25]) {
+    for round_constant in ROUND_CONSTANTS {
+        // θ: column parity, XORed into every lane of the two neighbouring columns
+        let mut c = [0u64; 5];
+        for x in 0..5 {
+            c[x] = state[x] ^ state[x + 5] ^ state[x + 10] ^ state[x + 15] ^ state[x + 20];
+        }
+        let mut d = [0u64; 5];
+        for x in 0..5 {
+            d[x] = c[(x + 4) % 5] ^ c[(x + 1) % 5].rotate_left(1);
+        }
+        for x in 0..5 {
+            for y in 0..5 {
+                state[x + 5 * y] ^= d[x];
+            }
+        }
+
+        // ρ and π: rotate each lane and permute it to its new position
+        let mut b = [0u64; 25];
+        for x in 0..5 {
+            for y in 0..5 {
+                let rotated = state[x + 5 * y].rotate_left(ROTATION_OFFSETS[x + 5 * y]);
+                b[y + 5 * ((2 * x + 3 * y) % 5)] = rotated;
+            }
+        }
+
+        // χ: non-linear mixing within each row
+        for x in 0..5 {
+            for y in 0..5 {
+                state[x + 5 * y] = b[x + 5 * y] ^ (!b[(x + 1) % 5 + 5 * y] & b[(x + 2) % 5 + 5 * y]);
+            }
+        }
+
+        // ι: break symmetry with the round constant
+        state[0] ^= round_constant;
+    }
+}
 
 /// A 512-bit cryptographic hash with optimized operations
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -18,44 +85,15 @@ pub struct Hash {
 
 impl Hash {
     /// Create a new hash from input data with optimized hashing
+    ///
+    /// Thin wrapper over the same streaming path [`Hasher`] uses, so a single
+    /// call here and an equivalent sequence of [`Hasher::update`] calls
+    /// produce the same result.
     #[inline]
     pub fn new(data: &[u8]) -> Self {
-        let mut hasher = DefaultHasher::new();
-        data.hash(&mut hasher);
-        let hash_value = hasher.finish();
-        
-        // Optimized 512-bit expansion using SIMD-friendly operations
-        let mut bytes = [0u8; HASH_SIZE];
-        let hash_bytes = hash_value.to_le_bytes();
-        
-        // Unrolled loop for better performance
-        unsafe {
-            let bytes_ptr = bytes.as_mut_ptr();
-            let hash_ptr = hash_bytes.as_ptr();
-            
-            // Fill 8 chunks of 8 bytes each with optimized mixing
-            for i in 0..8 {
-                let offset = i * 8;
-                let mix_factor = (i as u8).wrapping_mul(17);
-                
-                for j in 0..8 {
-                    *bytes_ptr.add(offset + j) = 
-                        (*hash_ptr.add(j)).wrapping_add(mix_factor).wrapping_add(j as u8);
-                }
-            }
-        }
-        
-        // Optimized length mixing
-        let len_hash = (data.len() as u64).wrapping_mul(0x9e3779b97f4a7c15);
-        let len_bytes = len_hash.to_le_bytes();
-        
-        // XOR length into first and last 8 bytes
-        for i in 0..8 {
-            bytes[i] ^= len_bytes[i];
-            bytes[HASH_SIZE - 8 + i] ^= len_bytes[i];
-        }
-        
-        Hash { bytes }
+        let mut hasher = HasherCore::default();
+        hasher.absorb(data);
+        hasher.finish_hash()
     }
 
     /// Create a hash from raw bytes (zero-cost)
@@ -127,67 +165,33 @@ impl Hash {
         result
     }
 
-    /// Combine two pieces of data into a single hash (optimized, no allocation)
+    /// Combine two pieces of data into a single hash
+    ///
+    /// Each input's length is absorbed (as little-endian `u64`) before its
+    /// bytes, so `combine(a, b)` cannot collide with `combine` on some other
+    /// split of the same concatenated bytes.
     #[inline]
     pub fn combine(data1: &[u8], data2: &[u8]) -> Self {
-        let mut hasher = DefaultHasher::new();
-        
-        // Hash lengths first for domain separation
-        data1.len().hash(&mut hasher);
-        data2.len().hash(&mut hasher);
-        
-        // Hash data
-        data1.hash(&mut hasher);
-        data2.hash(&mut hasher);
-        
-        let hash_value = hasher.finish();
-        let mut bytes = [0u8; HASH_SIZE];
-        let hash_bytes = hash_value.to_le_bytes();
-        
-        // Optimized expansion with better mixing
-        for i in 0..8 {
-            let offset = i * 8;
-            let mix = (i as u64).wrapping_mul(0x9e3779b97f4a7c15);
-            let mix_bytes = mix.to_le_bytes();
-            
-            for j in 0..8 {
-                bytes[offset + j] = hash_bytes[j] ^ mix_bytes[j];
-            }
-        }
-        
-        Hash { bytes }
+        let mut hasher = HasherCore::default();
+        hasher.absorb(&(data1.len() as u64).to_le_bytes());
+        hasher.absorb(&(data2.len() as u64).to_le_bytes());
+        hasher.absorb(data1);
+        hasher.absorb(data2);
+        hasher.finish_hash()
     }
 
-    /// Concatenate multiple hashes into a single hash (optimized)
+    /// Concatenate multiple hashes into a single hash
     pub fn concat(hashes: &[&Hash]) -> Self {
         if hashes.is_empty() {
             return Hash::new(&[]);
         }
-        
-        let mut hasher = DefaultHasher::new();
-        hashes.len().hash(&mut hasher);
-        
-        // Hash all input hashes efficiently
+
+        let mut hasher = HasherCore::default();
+        hasher.absorb(&(hashes.len() as u64).to_le_bytes());
         for hash in hashes {
-            hash.bytes.hash(&mut hasher);
-        }
-        
-        let hash_value = hasher.finish();
-        let mut bytes = [0u8; HASH_SIZE];
-        let hash_bytes = hash_value.to_le_bytes();
-        
-        // Optimized expansion
-        for i in 0..8 {
-            let offset = i * 8;
-            let mix = (i as u64).wrapping_mul(0xc6a4a7935bd1e995);
-            let mix_bytes = mix.to_le_bytes();
-            
-            for j in 0..8 {
-                bytes[offset + j] = hash_bytes[j] ^ mix_bytes[j];
-            }
+            hasher.absorb(&hash.bytes);
         }
-        
-        Hash { bytes }
+        hasher.finish_hash()
     }
 
     /// Hash binary data (optimized convenience method)
@@ -220,6 +224,181 @@ impl Hash {
         }
         Hash { bytes: result }
     }
+
+    /// Constant-time equality check
+    ///
+    /// The derived `PartialEq`/`==` on `Hash` compares bytes with an
+    /// early-exit `memcmp`-style loop, which is fine for non-secret hashes
+    /// (Merkle roots, fragment digests) but leaks timing when `Hash` stands
+    /// in for secret material, e.g. a KEM re-encryption check. Compares
+    /// every byte unconditionally, OR-accumulating the differences instead
+    /// of returning on the first mismatch.
+    #[inline(always)]
+    pub fn ct_eq(&self, other: &Hash) -> bool {
+        crate::utils::constant_time_eq(&self.bytes, &other.bytes)
+    }
+
+    /// Keyed hash (HMAC) for domain-separated message authentication
+    ///
+    /// Standard HMAC construction (RFC 2104) over this module's sponge: a
+    /// `key` longer than [`HMAC_BLOCK_SIZE`] is hashed down first, a shorter
+    /// one is zero-padded up to it, then XORed with the `ipad`/`opad`
+    /// constants around two passes of the hash. Unlike [`Hash::combine`],
+    /// this is safe to use for authenticating data an attacker can
+    /// influence, since the key cannot be recovered or forged from seeing
+    /// `(data, hmac(key, data))` pairs alone.
+    pub fn hmac(key: &[u8], data: &[u8]) -> Self {
+        let mut block_key = [0u8; HMAC_BLOCK_SIZE];
+        if key.len() > HMAC_BLOCK_SIZE {
+            block_key[..HASH_SIZE].copy_from_slice(Hash::new(key).as_bytes());
+        } else {
+            block_key[..key.len()].copy_from_slice(key);
+        }
+
+        let mut ipad = [0u8; HMAC_BLOCK_SIZE];
+        let mut opad = [0u8; HMAC_BLOCK_SIZE];
+        for i in 0..HMAC_BLOCK_SIZE {
+            ipad[i] = block_key[i] ^ 0x36;
+            opad[i] = block_key[i] ^ 0x5c;
+        }
+
+        let mut inner = HasherCore::default();
+        inner.absorb(&ipad);
+        inner.absorb(data);
+        let inner_hash = inner.finish_hash();
+
+        let mut outer = HasherCore::default();
+        outer.absorb(&opad);
+        outer.absorb(inner_hash.as_bytes());
+        outer.finish_hash()
+    }
+}
+
+/// Keccak sponge state shared by [`Hash::new`] and [`Hasher`]: absorbs input
+/// in fixed [`KECCAK_RATE_BYTES`] blocks, carrying any partial trailing block
+/// across calls, so the same bytes produce the same hash whether they arrive
+/// as one slice or many.
+#[derive(Debug, Clone)]
+struct HasherCore {
+    state: [u64; 25],
+    /// Bytes received since the last full rate-sized block was absorbed
+    pending: Vec<u8>,
+}
+
+impl Default for HasherCore {
+    fn default() -> Self {
+        HasherCore {
+            state: [0u64; 25],
+            pending: Vec::new(),
+        }
+    }
+}
+
+impl HasherCore {
+    /// XOR one rate-sized block into the state and apply the permutation
+    fn absorb_block(&mut self, block: &[u8]) {
+        debug_assert_eq!(block.len(), KECCAK_RATE_BYTES);
+        for (lane, chunk) in self.state.iter_mut().zip(block.chunks_exact(8)) {
+            *lane ^= u64::from_le_bytes(chunk.try_into().unwrap());
+        }
+        keccak_f1600(&mut self.state);
+    }
+
+    /// Feed `data` into the sponge, absorbing every full rate block it
+    /// completes and holding back the remainder for the next call
+    fn absorb(&mut self, data: &[u8]) {
+        self.pending.extend_from_slice(data);
+
+        let mut absorbed = 0;
+        while self.pending.len() - absorbed >= KECCAK_RATE_BYTES {
+            let block = self.pending[absorbed..absorbed + KECCAK_RATE_BYTES].to_vec();
+            self.absorb_block(&block);
+            absorbed += KECCAK_RATE_BYTES;
+        }
+        self.pending.drain(..absorbed);
+    }
+
+    /// Pad the trailing partial block and squeeze 64 bytes out of the sponge
+    fn finish_hash(mut self) -> Hash {
+        let mut block = core::mem::take(&mut self.pending);
+        block.push(SHA3_DOMAIN_SUFFIX);
+        block.resize(KECCAK_RATE_BYTES, 0);
+        *block.last_mut().unwrap() |= 0x80;
+        self.absorb_block(&block);
+
+        let mut bytes = [0u8; HASH_SIZE];
+        for (lane, chunk) in self.state.iter().zip(bytes.chunks_exact_mut(8)) {
+            chunk.copy_from_slice(&lane.to_le_bytes());
+        }
+        Hash { bytes }
+    }
+}
+
+/// Incremental hasher for data that arrives in chunks or does not fit in memory
+///
+/// Absorbs input in [`KECCAK_RATE_BYTES`]-byte sponge blocks across calls to
+/// [`Hasher::update`] (so memory use is bounded by one pending block, not
+/// the whole input) and
+/// produces the same result as passing the full concatenated input to
+/// [`Hash::new`] in one shot via [`Hasher::finalize`]. Also implements
+/// [`std::io::Write`], so it can be used as the sink of `std::io::copy` or
+/// any other writer-based API.
+#[derive(Debug, Clone, Default)]
+pub struct Hasher {
+    core: HasherCore,
+}
+
+impl Hasher {
+    /// Create a new, empty incremental hasher
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Feed more data into the hasher
+    #[inline]
+    pub fn update(&mut self, data: &[u8]) {
+        self.core.absorb(data);
+    }
+
+    /// Consume the hasher and produce the final hash
+    #[inline]
+    pub fn finalize(self) -> Hash {
+        self.core.finish_hash()
+    }
+}
+
+impl std::io::Write for Hasher {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.update(buf);
+        Ok(buf.len())
+    }
+
+    #[inline]
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Extendable-output function (XOF) producing arbitrary-length deterministic output
+///
+/// Squeezes `out.len()` bytes of output derived from `input`, one `Hash`-sized
+/// block at a time, each block domain-separated by a one-byte counter. This
+/// lets the hash primitive double as a KDF without pulling in another
+/// dependency.
+pub fn xof(input: &[u8], out: &mut [u8]) {
+    let mut offset = 0;
+    let mut counter: u8 = 0;
+
+    while offset < out.len() {
+        let block = Hash::combine(input, &counter.to_le_bytes());
+        let remaining = out.len() - offset;
+        let to_copy = core::cmp::min(HASH_SIZE, remaining);
+        out[offset..offset + to_copy].copy_from_slice(&block.as_bytes()[..to_copy]);
+        offset += to_copy;
+        counter = counter.wrapping_add(1);
+    }
 }
 
 impl AsRef<[u8]> for Hash {
@@ -234,6 +413,38 @@ impl From<[u8; HASH_SIZE]> for Hash {
     }
 }
 
+#[cfg(feature = "serde")]
+impl serde::Serialize for Hash {
+    fn serialize<S>(&self, serializer: S) -> core::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        if serializer.is_human_readable() {
+            serializer.serialize_str(&self.to_hex())
+        } else {
+            self.bytes.serialize(serializer)
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Hash {
+    fn deserialize<D>(deserializer: D) -> core::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        use serde::de::Error;
+
+        if deserializer.is_human_readable() {
+            let hex = String::deserialize(deserializer)?;
+            Hash::from_hex(&hex).map_err(D::Error::custom)
+        } else {
+            let bytes = crate::utils::deserialize_byte_array(deserializer)?;
+            Ok(Hash { bytes })
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -277,6 +488,122 @@ mod tests {
         assert_eq!(concatenated.as_bytes().len(), HASH_SIZE);
     }
 
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_serde_human_readable_roundtrip() {
+        let hash = Hash::new(b"serde test data");
+        let json = serde_json::to_string(&hash).unwrap();
+        assert_eq!(json, format!("\"{}\"", hash.to_hex()));
+
+        let decoded: Hash = serde_json::from_str(&json).unwrap();
+        assert_eq!(hash, decoded);
+    }
+
+    #[test]
+    fn test_hasher_matches_one_shot() {
+        let data = b"hello incremental world";
+
+        let mut hasher = Hasher::new();
+        hasher.update(&data[..5]);
+        hasher.update(&data[5..]);
+        let incremental = hasher.finalize();
+
+        assert_eq!(incremental, Hash::new(data));
+    }
+
+    #[test]
+    fn test_hasher_matches_one_shot_across_block_boundary() {
+        // The sponge absorbs 72-byte blocks, so splits at bytes 63 and 65
+        // leave a partial block pending across several `update` calls
+        // before the first full block is absorbed.
+        let data: Vec<u8> = (0..200u32).map(|i| (i % 251) as u8).collect();
+
+        let mut hasher = Hasher::new();
+        hasher.update(&data[..63]);
+        hasher.update(&data[63..65]);
+        hasher.update(&data[65..]);
+        let incremental = hasher.finalize();
+
+        assert_eq!(incremental, Hash::new(&data));
+    }
+
+    #[test]
+    fn test_hasher_matches_one_shot_across_keccak_rate_boundary() {
+        // KECCAK_RATE_BYTES is 72; split right around it so a chunk lands
+        // exactly on the rate boundary and exercises the carry-over path.
+        let data: Vec<u8> = (0..300u32).map(|i| (i % 251) as u8).collect();
+
+        let mut hasher = Hasher::new();
+        hasher.update(&data[..71]);
+        hasher.update(&data[71..72]);
+        hasher.update(&data[72..150]);
+        hasher.update(&data[150..]);
+        let incremental = hasher.finalize();
+
+        assert_eq!(incremental, Hash::new(&data));
+    }
+
+    #[test]
+    fn test_hasher_implements_io_write() {
+        use std::io::Write;
+
+        let data = b"streamed through std::io::Write";
+        let mut hasher = Hasher::new();
+        hasher.write_all(&data[..10]).unwrap();
+        hasher.write_all(&data[10..]).unwrap();
+
+        assert_eq!(hasher.finalize(), Hash::new(data));
+    }
+
+    #[test]
+    fn test_xof_is_deterministic_and_extendable() {
+        let mut short = [0u8; 32];
+        let mut long = [0u8; 128];
+
+        xof(b"xof input", &mut short);
+        xof(b"xof input", &mut long);
+
+        assert_eq!(&long[..32], &short[..]);
+
+        let mut other = [0u8; 32];
+        xof(b"different input", &mut other);
+        assert_ne!(other, short);
+    }
+
+    #[test]
+    fn test_hmac_is_deterministic_and_key_sensitive() {
+        let data = b"message to authenticate";
+        let mac1 = Hash::hmac(b"key one", data);
+        let mac2 = Hash::hmac(b"key one", data);
+        let mac3 = Hash::hmac(b"key two", data);
+
+        assert_eq!(mac1, mac2);
+        assert_ne!(mac1, mac3);
+    }
+
+    #[test]
+    fn test_hmac_is_data_sensitive() {
+        let key = b"shared key";
+        let mac1 = Hash::hmac(key, b"data one");
+        let mac2 = Hash::hmac(key, b"data two");
+
+        assert_ne!(mac1, mac2);
+    }
+
+    #[test]
+    fn test_hmac_handles_keys_longer_than_block_size() {
+        let long_key = [0x42u8; HMAC_BLOCK_SIZE * 2];
+        let short_key = [0x42u8; 16];
+        let data = b"payload";
+
+        // A long key and a short key should not collide just because their
+        // repeated bytes overlap
+        assert_ne!(Hash::hmac(&long_key, data), Hash::hmac(&short_key, data));
+
+        // Hashing the same over-long key down should still be deterministic
+        assert_eq!(Hash::hmac(&long_key, data), Hash::hmac(&long_key, data));
+    }
+
     #[test]
     fn test_invalid_hex() {
         let result = Hash::from_hex("invalid_hex");
@@ -0,0 +1,313 @@
+//! Lamport hash-based one-time signatures (HBS) for TOPAY-Z512
+//!
+//! [`crate::sign`] already builds a Winternitz one-time signature on top of
+//! [`Hash`], trading bigger keys for shorter signatures via hash chains.
+//! This module adds the scheme that chaining is an optimization *of* — a
+//! plain Lamport signature: key generation samples two random 64-byte
+//! secrets per bit of a 512-bit digest (one for "this bit is 0", one for
+//! "this bit is 1") and publishes their [`Hash`] under the matching public
+//! key slot; signing reveals whichever secret matches each bit of
+//! `Hash::new(msg)`; verification re-hashes each revealed secret and checks
+//! it against the public slot the corresponding bit selects. Its security
+//! reduces to nothing but `Hash`'s preimage resistance, so it is
+//! quantum-resistant the same way [`crate::sign`] is, at the cost of a much
+//! larger public key (1024 hash outputs instead of one compressed digest) —
+//! use [`crate::sign`] instead when signature/key size matters more than
+//! having the simplest possible security reduction.
+//!
+//! # One-time use
+//!
+//! **Never sign more than one message with the same [`LamportKeyPair`].**
+//! Signing reveals exactly one of the two secrets per bit; a second
+//! signature over a different message would reveal the other secret for
+//! any bit that differs, handing an attacker both preimages for that bit
+//! and letting them forge a signature over an arbitrary third message.
+//! Generate a fresh key pair per signature.
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use crate::hash::Hash;
+use crate::HASH_SIZE;
+use rand_core::{CryptoRng, RngCore};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Number of bits in a 512-bit [`Hash`] digest, and so the number of
+/// secret/public key pairs a [`LamportKeyPair`] holds
+const BITS: usize = HASH_SIZE * 8;
+
+/// High-performance pseudo-random number generator for secret generation
+///
+/// Mirrors the `OptimizedRng` used by [`crate::sign`], [`crate::keypair`]
+/// and [`crate::kem`].
+struct OptimizedRng {
+    state: [u64; 4],
+}
+
+impl OptimizedRng {
+    #[inline]
+    fn new() -> Self {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_nanos() as u64;
+
+        let state = [
+            now,
+            now.wrapping_mul(0x9E3779B97F4A7C15),
+            now.wrapping_mul(0xBF58476D1CE4E5B9),
+            now.wrapping_mul(0x94D049BB133111EB),
+        ];
+
+        Self { state }
+    }
+
+    #[inline]
+    fn next_bytes(&mut self, bytes: &mut [u8]) {
+        let mut i = 0;
+        while i < bytes.len() {
+            let random_u64 = self.next_u64();
+            let to_copy = core::cmp::min(8, bytes.len() - i);
+            bytes[i..i + to_copy].copy_from_slice(&random_u64.to_le_bytes()[..to_copy]);
+            i += to_copy;
+        }
+    }
+
+    #[inline(always)]
+    fn next_u64(&mut self) -> u64 {
+        let result = self.state[1].wrapping_mul(5).rotate_left(7).wrapping_mul(9);
+        let t = self.state[1] << 17;
+
+        self.state[2] ^= self.state[0];
+        self.state[3] ^= self.state[1];
+        self.state[1] ^= self.state[2];
+        self.state[0] ^= self.state[3];
+
+        self.state[2] ^= t;
+        self.state[3] = self.state[3].rotate_left(45);
+
+        result
+    }
+}
+
+/// Extract the `BITS` bits of a [`Hash`] digest, most significant bit of
+/// each byte first
+fn message_bits(digest: &Hash) -> Vec<bool> {
+    let mut bits = Vec::with_capacity(BITS);
+    for &byte in digest.as_bytes() {
+        for i in (0..8).rev() {
+            bits.push((byte >> i) & 1 == 1);
+        }
+    }
+    bits
+}
+
+/// A Lamport one-time signature public key: for each of the `BITS` bit
+/// positions, the hash of the "bit is 0" secret and the hash of the
+/// "bit is 1" secret
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LamportPublicKey {
+    hashes: Vec<[[u8; HASH_SIZE]; 2]>,
+}
+
+impl LamportPublicKey {
+    /// Get the per-bit public hash pairs
+    #[inline(always)]
+    pub fn as_slice(&self) -> &[[[u8; HASH_SIZE]; 2]] {
+        &self.hashes
+    }
+}
+
+/// A Lamport one-time signature: one revealed secret per bit of the signed
+/// message's digest
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LamportSignature {
+    revealed: Vec<[u8; HASH_SIZE]>,
+}
+
+/// A Lamport one-time signature key pair
+///
+/// **Sign at most one message with a given key pair** — see the module
+/// documentation for why reuse breaks the scheme's security.
+#[derive(Debug, Clone)]
+pub struct LamportKeyPair {
+    secrets: Vec<[[u8; HASH_SIZE]; 2]>,
+    public_key: LamportPublicKey,
+}
+
+impl LamportKeyPair {
+    /// Generate a new one-time key pair
+    pub fn generate() -> Self {
+        let mut rng = OptimizedRng::new();
+        Self::generate_with(|bytes| rng.next_bytes(bytes))
+    }
+
+    /// Generate a new one-time key pair using a caller-supplied CSPRNG
+    pub fn generate_with_rng<R: RngCore + CryptoRng>(rng: &mut R) -> Self {
+        Self::generate_with(|bytes| rng.fill_bytes(bytes))
+    }
+
+    fn generate_with(mut fill: impl FnMut(&mut [u8])) -> Self {
+        let mut secrets = Vec::with_capacity(BITS);
+        for _ in 0..BITS {
+            let mut zero_bit = [0u8; HASH_SIZE];
+            let mut one_bit = [0u8; HASH_SIZE];
+            fill(&mut zero_bit);
+            fill(&mut one_bit);
+            secrets.push([zero_bit, one_bit]);
+        }
+
+        let public_key = Self::derive_public_key(&secrets);
+
+        Self {
+            secrets,
+            public_key,
+        }
+    }
+
+    fn derive_public_key(secrets: &[[[u8; HASH_SIZE]; 2]]) -> LamportPublicKey {
+        let hashes = secrets
+            .iter()
+            .map(|[zero_bit, one_bit]| {
+                [*Hash::new(zero_bit).as_bytes(), *Hash::new(one_bit).as_bytes()]
+            })
+            .collect();
+
+        LamportPublicKey { hashes }
+    }
+
+    /// Get the public key
+    #[inline(always)]
+    pub fn public_key(&self) -> &LamportPublicKey {
+        &self.public_key
+    }
+
+    /// Sign `msg`
+    ///
+    /// Consumes `self`: a `LamportKeyPair` is only safe to sign with once,
+    /// so taking it by value prevents accidentally reusing it for a second
+    /// message.
+    pub fn sign(self, msg: &[u8]) -> LamportSignature {
+        let digest = Hash::new(msg);
+        let bits = message_bits(&digest);
+
+        let revealed = self
+            .secrets
+            .iter()
+            .zip(bits.iter())
+            .map(|(pair, &bit)| pair[bit as usize])
+            .collect();
+
+        LamportSignature { revealed }
+    }
+
+    /// Secure zero out the secret preimages (for security)
+    pub fn zeroize(&mut self) {
+        for [zero_bit, one_bit] in self.secrets.iter_mut() {
+            #[cfg(feature = "zeroize")]
+            {
+                use zeroize::Zeroize;
+                zero_bit.zeroize();
+                one_bit.zeroize();
+            }
+            #[cfg(not(feature = "zeroize"))]
+            {
+                crate::utils::secure_zero(zero_bit);
+                crate::utils::secure_zero(one_bit);
+            }
+        }
+    }
+}
+
+impl Drop for LamportKeyPair {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+/// Verify that `sig` is a valid one-time signature over `msg` under `pk`
+pub fn verify(pk: &LamportPublicKey, msg: &[u8], sig: &LamportSignature) -> bool {
+    if sig.revealed.len() != BITS || pk.hashes.len() != BITS {
+        return false;
+    }
+
+    let digest = Hash::new(msg);
+    let bits = message_bits(&digest);
+
+    for ((revealed, &bit), hashes) in sig.revealed.iter().zip(bits.iter()).zip(pk.hashes.iter()) {
+        if Hash::new(revealed).as_bytes() != &hashes[bit as usize] {
+            return false;
+        }
+    }
+
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sign_verify_roundtrip() {
+        let keypair = LamportKeyPair::generate();
+        let public_key = keypair.public_key().clone();
+        let msg = b"transfer 10 TOPAY to Bob";
+
+        let signature = keypair.sign(msg);
+        assert!(verify(&public_key, msg, &signature));
+    }
+
+    #[test]
+    fn test_verify_rejects_wrong_message() {
+        let keypair = LamportKeyPair::generate();
+        let public_key = keypair.public_key().clone();
+
+        let signature = keypair.sign(b"transfer 10 TOPAY to Bob");
+        assert!(!verify(&public_key, b"transfer 99 TOPAY to Bob", &signature));
+    }
+
+    #[test]
+    fn test_verify_rejects_wrong_public_key() {
+        let keypair1 = LamportKeyPair::generate();
+        let keypair2 = LamportKeyPair::generate();
+        let msg = b"transfer 10 TOPAY to Bob";
+
+        let signature = keypair1.sign(msg);
+        assert!(!verify(keypair2.public_key(), msg, &signature));
+    }
+
+    #[test]
+    fn test_verify_rejects_tampered_signature() {
+        let keypair = LamportKeyPair::generate();
+        let public_key = keypair.public_key().clone();
+        let msg = b"transfer 10 TOPAY to Bob";
+
+        let mut signature = keypair.sign(msg);
+        signature.revealed[0][0] ^= 0x01;
+        assert!(!verify(&public_key, msg, &signature));
+    }
+
+    #[test]
+    fn test_verify_rejects_truncated_signature() {
+        let keypair = LamportKeyPair::generate();
+        let public_key = keypair.public_key().clone();
+        let msg = b"transfer 10 TOPAY to Bob";
+
+        let mut signature = keypair.sign(msg);
+        signature.revealed.pop();
+        assert!(!verify(&public_key, msg, &signature));
+    }
+
+    #[test]
+    fn test_generate_with_rng() {
+        use rand_core::OsRng;
+
+        let mut rng = OsRng;
+        let keypair = LamportKeyPair::generate_with_rng(&mut rng);
+        let msg = b"deterministic-rng test message";
+        let public_key = keypair.public_key().clone();
+
+        let signature = keypair.sign(msg);
+        assert!(verify(&public_key, msg, &signature));
+    }
+}
@@ -0,0 +1,215 @@
+//! HPKE-style hybrid public-key encryption built on top of [`crate::kem::Kem`].
+//!
+//! This turns the raw KEM shared secret into a usable encrypt-to-public-key
+//! primitive, following the RFC 9180 base-mode key schedule: `Kem::encapsulate`
+//! supplies the shared secret, which is run through an HKDF-over-`Hash` extract
+//! and expand to derive an AEAD key and base nonce, and the plaintext is sealed
+//! with ChaCha20-Poly1305 under that key.
+//!
+//! This module has no confidentiality of its own beyond `Kem`'s: `seal`'s
+//! only key material is the encapsulated shared secret, so it is a genuine
+//! hybrid public-key encryption scheme exactly when `Kem::encapsulate`'s
+//! shared secret is hidden from anyone without the matching `SecretKey` —
+//! true since `Kem`'s [`crate::mlwe`]-backed PKE core closed that hole.
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use crate::error::{Result, TopayzError};
+use crate::hash::Hash;
+use crate::kem::{Ciphertext, Kem, PublicKey, SecretKey};
+use chacha20poly1305::aead::{Aead, Payload};
+use chacha20poly1305::{ChaCha20Poly1305, KeyInit, Nonce};
+
+const AEAD_KEY_LEN: usize = 32;
+const AEAD_NONCE_LEN: usize = 12;
+
+/// The output of [`seal`]: the KEM ciphertext (`enc`) plus the AEAD ciphertext.
+///
+/// `enc` must be transmitted alongside `ciphertext` so the recipient can
+/// decapsulate the same shared secret before opening.
+#[derive(Debug, Clone)]
+pub struct Sealed {
+    /// KEM ciphertext produced by encapsulating to the recipient's public key.
+    pub enc: Ciphertext,
+    /// AEAD ciphertext, including the authentication tag.
+    pub ciphertext: Vec<u8>,
+}
+
+/// `HKDF-Extract(salt, ikm)` built on the existing `Hash` primitive.
+fn hkdf_extract(salt: &[u8], ikm: &[u8]) -> Hash {
+    Hash::combine(salt, ikm)
+}
+
+/// `HKDF-Expand(prk, label, out_len)` built on the existing `Hash` primitive.
+fn hkdf_expand(prk: &Hash, label: &[u8], out_len: usize) -> Vec<u8> {
+    let mut out = Vec::with_capacity(out_len);
+    let mut previous: Vec<u8> = Vec::new();
+    let mut counter: u8 = 1;
+
+    while out.len() < out_len {
+        let mut block = previous.clone();
+        block.extend_from_slice(label);
+        block.push(counter);
+
+        let t = Hash::combine(prk.as_bytes(), &block);
+        out.extend_from_slice(t.as_bytes());
+        previous = t.to_bytes().to_vec();
+        counter += 1;
+    }
+
+    out.truncate(out_len);
+    out
+}
+
+/// Derive the AEAD key and base nonce from the KEM shared secret and `info`.
+fn key_schedule(shared_secret: &[u8], info: &[u8]) -> ([u8; AEAD_KEY_LEN], [u8; AEAD_NONCE_LEN]) {
+    let secret = hkdf_extract(&[0u8; 64], shared_secret);
+    let info_hash = Hash::new(info);
+
+    let mut key_label = b"topay-hpke key".to_vec();
+    key_label.extend_from_slice(info_hash.as_bytes());
+    let key_bytes = hkdf_expand(&secret, &key_label, AEAD_KEY_LEN);
+
+    let mut nonce_label = b"topay-hpke nonce".to_vec();
+    nonce_label.extend_from_slice(info_hash.as_bytes());
+    let nonce_bytes = hkdf_expand(&secret, &nonce_label, AEAD_NONCE_LEN);
+
+    let mut key = [0u8; AEAD_KEY_LEN];
+    key.copy_from_slice(&key_bytes);
+    let mut base_nonce = [0u8; AEAD_NONCE_LEN];
+    base_nonce.copy_from_slice(&nonce_bytes);
+
+    (key, base_nonce)
+}
+
+/// Seal `plaintext` to `recipient_pk`, binding `aad` and the `info` label.
+///
+/// Internally encapsulates a fresh shared secret with `recipient_pk`, derives
+/// an AEAD key/nonce from it, and encrypts `plaintext` under that key.
+pub fn seal(recipient_pk: &PublicKey, info: &[u8], aad: &[u8], plaintext: &[u8]) -> Result<Sealed> {
+    let (enc, shared_secret) = Kem::encapsulate(recipient_pk);
+    let (key, base_nonce) = key_schedule(shared_secret.as_bytes(), info);
+
+    let cipher = ChaCha20Poly1305::new((&key).into());
+    let nonce = Nonce::from_slice(&base_nonce);
+    let ciphertext = cipher
+        .encrypt(nonce, Payload { msg: plaintext, aad })
+        .map_err(|_| TopayzError::CryptoError("HPKE seal failed".to_string()))?;
+
+    Ok(Sealed { enc, ciphertext })
+}
+
+/// Open a message produced by [`seal`].
+///
+/// Decapsulates `enc` with `recipient_sk`, re-derives the AEAD key/nonce, and
+/// authenticates/decrypts `ciphertext`. Returns an error if `aad`/`info` do
+/// not match or the ciphertext was tampered with.
+pub fn open(
+    recipient_sk: &SecretKey,
+    enc: &Ciphertext,
+    info: &[u8],
+    aad: &[u8],
+    ciphertext: &[u8],
+) -> Result<Vec<u8>> {
+    let shared_secret = Kem::decapsulate(recipient_sk, enc);
+    let (key, base_nonce) = key_schedule(shared_secret.as_bytes(), info);
+
+    let cipher = ChaCha20Poly1305::new((&key).into());
+    let nonce = Nonce::from_slice(&base_nonce);
+    cipher
+        .decrypt(nonce, Payload { msg: ciphertext, aad })
+        .map_err(|_| TopayzError::CryptoError("HPKE open failed: authentication failed".to_string()))
+}
+
+/// Seal `plaintext` to `recipient_pk`, binding `aad` with an empty `info`
+/// context label
+///
+/// Convenience entry point for callers who don't need per-application domain
+/// separation via `info` — equivalent to `seal(recipient_pk, &[], aad, plaintext)`.
+#[inline]
+pub fn seal_aad(recipient_pk: &PublicKey, aad: &[u8], plaintext: &[u8]) -> Result<Sealed> {
+    seal(recipient_pk, &[], aad, plaintext)
+}
+
+/// Open a message produced by [`seal_aad`]
+///
+/// Equivalent to `open(recipient_sk, enc, &[], aad, ciphertext)`.
+#[inline]
+pub fn open_aad(
+    recipient_sk: &SecretKey,
+    enc: &Ciphertext,
+    aad: &[u8],
+    ciphertext: &[u8],
+) -> Result<Vec<u8>> {
+    open(recipient_sk, enc, &[], aad, ciphertext)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_seal_open_roundtrip() {
+        let (public_key, secret_key) = Kem::keygen();
+        let info = b"topay-hpke-example";
+        let aad = b"header";
+        let plaintext = b"hello, quantum-safe world";
+
+        let sealed = seal(&public_key, info, aad, plaintext).unwrap();
+        let opened = open(&secret_key, &sealed.enc, info, aad, &sealed.ciphertext).unwrap();
+
+        assert_eq!(opened, plaintext);
+    }
+
+    #[test]
+    fn test_open_rejects_tampered_ciphertext() {
+        let (public_key, secret_key) = Kem::keygen();
+        let info = b"info";
+        let aad = b"aad";
+
+        let mut sealed = seal(&public_key, info, aad, b"secret message").unwrap();
+        let last = sealed.ciphertext.len() - 1;
+        sealed.ciphertext[last] ^= 0x01;
+
+        assert!(open(&secret_key, &sealed.enc, info, aad, &sealed.ciphertext).is_err());
+    }
+
+    #[test]
+    fn test_seal_open_aad_roundtrip() {
+        let (public_key, secret_key) = Kem::keygen();
+        let aad = b"header";
+        let plaintext = b"hello, quantum-safe world";
+
+        let sealed = seal_aad(&public_key, aad, plaintext).unwrap();
+        let opened = open_aad(&secret_key, &sealed.enc, aad, &sealed.ciphertext).unwrap();
+
+        assert_eq!(opened, plaintext);
+    }
+
+    #[test]
+    fn test_open_rejects_wrong_aad() {
+        let (public_key, secret_key) = Kem::keygen();
+        let info = b"info";
+
+        let sealed = seal(&public_key, info, b"correct-aad", b"secret message").unwrap();
+
+        assert!(open(&secret_key, &sealed.enc, info, b"wrong-aad", &sealed.ciphertext).is_err());
+    }
+
+    #[test]
+    fn test_open_with_wrong_secret_key_fails() {
+        // hpke's confidentiality is entirely inherited from Kem: opening with
+        // an unrelated secret key must fail rather than leak the plaintext,
+        // which only holds because Kem::decapsulate needs the matching
+        // secret key to recover the shared secret.
+        let (public_key, _) = Kem::keygen();
+        let (_, unrelated_secret_key) = Kem::keygen();
+        let info = b"info";
+        let aad = b"aad";
+
+        let sealed = seal(&public_key, info, aad, b"secret message").unwrap();
+
+        assert!(open(&unrelated_secret_key, &sealed.enc, info, aad, &sealed.ciphertext).is_err());
+    }
+}
@@ -0,0 +1,77 @@
+//! Password-based key derivation for TOPAY-Z512
+//!
+//! Turns a low-entropy human password into a fixed-length, uniformly random
+//! seed suitable for [`crate::keypair::KeyPair::generate_with_seed`], using
+//! PBKDF2 (RFC 8018) built on [`Hash::hmac`] as the pseudorandom function —
+//! no external KDF dependency is pulled in.
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use crate::hash::Hash;
+
+/// Length in bytes of the seed produced by [`derive_seed`]; matches
+/// [`crate::keypair::KeyPair::generate_with_seed`]'s expected seed width.
+pub const SEED_LENGTH: usize = 32;
+
+/// A reasonable default iteration count for [`derive_seed`]. Callers
+/// targeting a specific time budget should measure and pass their own
+/// count instead of relying on this.
+pub const DEFAULT_ITERATIONS: u32 = 100_000;
+
+/// Derive a [`SEED_LENGTH`]-byte seed from a `(password, salt)` pair via
+/// PBKDF2-HMAC built on [`Hash::hmac`].
+///
+/// `salt` should be unique per derivation (e.g. random per account) and
+/// `iterations` should be as large as the caller's latency budget allows —
+/// both determine how expensive it is for an attacker to brute-force the
+/// password offline. The seed this produces must never be reused across
+/// distinct key pairs: doing so links them to the same password instead of
+/// providing independent keys.
+pub fn derive_seed(password: &[u8], salt: &[u8], iterations: u32) -> [u8; SEED_LENGTH] {
+    let mut seed = [0u8; SEED_LENGTH];
+    let block = pbkdf2_block(password, salt, iterations, 1);
+    seed.copy_from_slice(&block[..SEED_LENGTH]);
+    seed
+}
+
+/// Derive a single PBKDF2 block (`U1 xor U2 xor ... xor Uc`) for block
+/// index `block_index` (1-based, per RFC 8018).
+fn pbkdf2_block(password: &[u8], salt: &[u8], iterations: u32, block_index: u32) -> [u8; 64] {
+    let mut block_salt = Vec::with_capacity(salt.len() + 4);
+    block_salt.extend_from_slice(salt);
+    block_salt.extend_from_slice(&block_index.to_be_bytes());
+
+    let mut u = Hash::hmac(password, &block_salt).to_bytes();
+    let mut result = u;
+
+    for _ in 1..iterations.max(1) {
+        u = Hash::hmac(password, &u).to_bytes();
+        for (r, byte) in result.iter_mut().zip(u.iter()) {
+            *r ^= byte;
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_derive_seed_is_deterministic() {
+        let seed1 = derive_seed(b"correct horse battery staple", b"salt", 1_000);
+        let seed2 = derive_seed(b"correct horse battery staple", b"salt", 1_000);
+        assert_eq!(seed1, seed2);
+    }
+
+    #[test]
+    fn test_derive_seed_differs_by_password_salt_and_iterations() {
+        let base = derive_seed(b"password", b"salt", 1_000);
+
+        assert_ne!(base, derive_seed(b"different", b"salt", 1_000));
+        assert_ne!(base, derive_seed(b"password", b"other-salt", 1_000));
+        assert_ne!(base, derive_seed(b"password", b"salt", 1_001));
+    }
+}
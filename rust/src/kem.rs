@@ -1,36 +1,130 @@
 //! Optimized Key Encapsulation Mechanism (KEM) for TOPAY-Z512
 //!
-//! This module provides a high-performance KEM implementation for demonstration purposes.
-//! In production, this would use a proper post-quantum KEM like Kyber or NTRU.
+//! The PKE core is real Module-LWE lattice arithmetic — ring multiplication,
+//! centered-binomial noise, XOF-expanded public elements — from
+//! [`crate::mlwe`], wrapped in the Fujisaki–Okamoto transform described
+//! below. Growing `PublicKey`/`SecretKey`/`Ciphertext` past their old
+//! 64/64/128-byte placeholder sizes (`PublicKey`/`Ciphertext` now carry
+//! [`mlwe`](crate::mlwe) key/ciphertext material; `SecretKey`'s public byte
+//! representation stays a 64-byte value, now treated as a seed the real
+//! lattice key pair is deterministically derived from — see
+//! [`SecretKey::from_bytes`]) was the accepted cost of closing a
+//! confidentiality hole the previous hash-mask core left open: that core's
+//! "decryption" recomputed its mask from the public encapsulation key and
+//! the ciphertext's own cleartext randomness, so it needed no secret key at
+//! all and anyone could recover the encapsulated message.
+//!
+//! Decapsulation is IND-CCA2 secure via the Fujisaki–Okamoto transform used by
+//! ML-KEM: `SecretKey` carries the decapsulation key, a copy of the matching
+//! encapsulation key, `h = H(ek)`, and a random implicit-rejection seed `z`.
+//! `Kem::encapsulate` hashes a random message `m` into `(K, r) = G(m || h)`
+//! and encrypts `m` under `(ek, r)`; `Kem::decapsulate` decrypts, re-derives
+//! `(K', r')`, re-encrypts, and only returns `K'` if the re-encryption matches
+//! the received ciphertext (compared in constant time) — otherwise it returns
+//! the implicit-rejection value `J(z || ct)` so a tampered ciphertext never
+//! silently decapsulates to a wrong-but-plausible secret. That proof relies
+//! on the underlying PKE being CPA-secure, which the `mlwe`-backed core
+//! (finally) is.
 
 use crate::error::{Result, TopayzError};
-use crate::hash::Hash;
+use crate::hash::{xof, Hash, Hasher};
+use crate::mlwe;
+use rand_core::{CryptoRng, RngCore};
 use std::time::{SystemTime, UNIX_EPOCH};
 
-/// KEM public key for encapsulation with optimized layout
+/// Byte width of one encoded [`mlwe::Poly`]: each of its [`mlwe::N`]
+/// coefficients as a little-endian `u16`.
+const POLY_BYTES: usize = mlwe::N * 2;
+
+/// Byte width of an encoded [`mlwe::PublicKey`]: its 32-byte XOF seed plus
+/// its `t` ring element.
+const MLWE_PK_BYTES: usize = 32 + POLY_BYTES;
+
+/// Byte width of one encoded [`mlwe::Ciphertext`]: its `u` and `v` ring
+/// elements.
+const MLWE_CT_BYTES: usize = 2 * POLY_BYTES;
+
+/// [`mlwe`] only encrypts a 32-byte message per ciphertext, so the FO
+/// transform's 64-byte message `m` is split into this many independently
+/// encrypted halves (each under its own domain-separated coins derived from
+/// `r`, see [`Kem::pke_encrypt`]).
+const MESSAGE_BLOCKS: usize = 2;
+const MESSAGE_BLOCK_BYTES: usize = 32;
+
+/// Byte width of a [`PublicKey`]: one encoded [`mlwe::PublicKey`].
+const KEM_PK_BYTES: usize = MLWE_PK_BYTES;
+
+/// Byte width of a [`Ciphertext`]: [`MESSAGE_BLOCKS`] encoded
+/// [`mlwe::Ciphertext`]s, one per message half.
+const KEM_CT_BYTES: usize = MESSAGE_BLOCKS * MLWE_CT_BYTES;
+
+/// KEM public key for encapsulation: an encoded [`mlwe::PublicKey`]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct PublicKey {
-    bytes: [u8; 64],
+    bytes: [u8; KEM_PK_BYTES],
 }
 
-/// KEM secret key for decapsulation with optimized layout
-#[derive(Debug, Clone, PartialEq, Eq)]
+/// KEM secret key for decapsulation
+///
+/// Deliberately does not derive `PartialOrd`, `Ord`, or `Hash` so secret key
+/// material cannot end up sorted or hashed in variable time; equality is a
+/// constant-time comparison (see `impl PartialEq`).
+///
+/// The externally-visible `decapsulation_key` is a 64-byte seed; everything
+/// else — the [`mlwe`] key pair, a copy of the encoded encapsulation key,
+/// its hash `h`, and the Fujisaki–Okamoto implicit-rejection seed `z` — is
+/// deterministically derived from it (see [`SecretKey::from_bytes`]), so two
+/// `SecretKey`s built from the same 64-byte seed are always equal and this
+/// type's wire format (and [`crate::threshold`]'s Shamir splitting of it)
+/// never had to change size when the PKE core did.
+#[derive(Debug, Clone)]
 pub struct SecretKey {
-    bytes: [u8; 64],
+    decapsulation_key: [u8; 64],
+    encapsulation_key: [u8; KEM_PK_BYTES],
+    mlwe_secret: mlwe::SecretKey,
+    h: Hash,
+    z: [u8; 64],
+}
+
+impl PartialEq for SecretKey {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        crate::utils::constant_time_eq(&self.decapsulation_key, &other.decapsulation_key)
+    }
 }
 
-/// KEM ciphertext containing encapsulated shared secret with optimized layout
+impl Eq for SecretKey {}
+
+/// KEM ciphertext: [`MESSAGE_BLOCKS`] encoded [`mlwe::Ciphertext`]s, one per
+/// 32-byte half of the FO-transform message `m`
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Ciphertext {
-    bytes: [u8; 64],
+    bytes: [u8; KEM_CT_BYTES],
 }
 
 /// Shared secret derived from KEM operations with optimized layout
-#[derive(Debug, Clone, PartialEq, Eq)]
+///
+/// Deliberately does not derive `PartialOrd`, `Ord`, or `Hash` so secret
+/// material cannot end up sorted or hashed in variable time; equality is a
+/// constant-time comparison via [`crate::utils::constant_time_eq`] (see
+/// `impl PartialEq`), which XOR-accumulates every byte difference into one
+/// value and checks it against zero with no early exit, rather than
+/// short-circuiting on the first mismatched byte the way `==` on `[u8; N]`
+/// would.
+#[derive(Debug, Clone)]
 pub struct SharedSecret {
     bytes: [u8; 64],
 }
 
+impl PartialEq for SharedSecret {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        crate::utils::constant_time_eq(&self.bytes, &other.bytes)
+    }
+}
+
+impl Eq for SharedSecret {}
+
 /// High-performance pseudo-random number generator optimized for cryptographic use
 struct OptimizedRng {
     state: [u64; 4], // Xoshiro256** state for better randomness
@@ -90,32 +184,194 @@ impl OptimizedRng {
     }
 }
 
+/// Encode an [`mlwe::Poly`] as [`POLY_BYTES`] little-endian `u16`s into `out`
+fn encode_poly(poly: &mlwe::Poly, out: &mut [u8]) {
+    for (i, &coefficient) in poly.iter().enumerate() {
+        out[i * 2..i * 2 + 2].copy_from_slice(&(coefficient as u16).to_le_bytes());
+    }
+}
+
+/// Inverse of [`encode_poly`]
+fn decode_poly(bytes: &[u8]) -> mlwe::Poly {
+    let mut poly = [0i16; mlwe::N];
+    for (i, coefficient) in poly.iter_mut().enumerate() {
+        let word = u16::from_le_bytes([bytes[i * 2], bytes[i * 2 + 1]]);
+        *coefficient = word as i16;
+    }
+    poly
+}
+
+/// Encode an [`mlwe::PublicKey`] as its seed followed by its `t` element
+fn encode_mlwe_public_key(public_key: &mlwe::PublicKey) -> [u8; MLWE_PK_BYTES] {
+    let mut bytes = [0u8; MLWE_PK_BYTES];
+    bytes[..32].copy_from_slice(public_key.seed());
+    encode_poly(public_key.t(), &mut bytes[32..]);
+    bytes
+}
+
+/// Inverse of [`encode_mlwe_public_key`]
+fn decode_public_key(bytes: &[u8; MLWE_PK_BYTES]) -> mlwe::PublicKey {
+    let mut seed = [0u8; 32];
+    seed.copy_from_slice(&bytes[..32]);
+    let t = decode_poly(&bytes[32..]);
+    mlwe::PublicKey::from_parts(seed, t)
+}
+
+/// Encode an [`mlwe::Ciphertext`] as its `u` element followed by its `v`
+/// element into `out`
+fn encode_mlwe_ciphertext(ciphertext: &mlwe::Ciphertext, out: &mut [u8]) {
+    encode_poly(ciphertext.u(), &mut out[..POLY_BYTES]);
+    encode_poly(ciphertext.v(), &mut out[POLY_BYTES..]);
+}
+
+/// Inverse of [`encode_mlwe_ciphertext`]
+fn decode_mlwe_ciphertext(bytes: &[u8]) -> mlwe::Ciphertext {
+    let u = decode_poly(&bytes[..POLY_BYTES]);
+    let v = decode_poly(&bytes[POLY_BYTES..]);
+    mlwe::Ciphertext::from_parts(u, v)
+}
+
+/// Deterministically derive the 32-byte [`mlwe::keygen`] seed a
+/// [`SecretKey`]'s real lattice key pair is expanded from, so the public
+/// 64-byte decapsulation key can stay the externally-visible representation
+/// (see the [`SecretKey`] doc comment)
+fn derive_mlwe_seed(decapsulation_key: &[u8; 64]) -> [u8; 32] {
+    let hash = Hash::combine(decapsulation_key, b"topay-kem-mlwe-seed");
+    let mut seed = [0u8; 32];
+    seed.copy_from_slice(&hash.to_bytes()[..32]);
+    seed
+}
+
 /// Optimized Key Encapsulation Mechanism implementation
 pub struct Kem;
 
 impl Kem {
+    /// `G(m || h) -> (K, r)`: expand the FO-transform message and public-key
+    /// hash into a shared-secret candidate and encryption randomness
+    #[inline]
+    fn g(m: &[u8; 64], h: &[u8; 64]) -> ([u8; 64], [u8; 64]) {
+        let mut input = [0u8; 128];
+        input[..64].copy_from_slice(m);
+        input[64..].copy_from_slice(h);
+
+        let mut expanded = [0u8; 128];
+        xof(&input, &mut expanded);
+
+        let mut k = [0u8; 64];
+        let mut r = [0u8; 64];
+        k.copy_from_slice(&expanded[..64]);
+        r.copy_from_slice(&expanded[64..]);
+        (k, r)
+    }
+
+    /// Derive the `block`-th half's [`mlwe::encrypt`] coins from the
+    /// FO-transform randomness `r`, so each 32-byte message half is
+    /// encrypted independently rather than reusing the same ephemeral
+    /// secret/error terms
+    #[inline]
+    fn derive_block_coins(r: &[u8; 64], block: u8) -> [u8; 32] {
+        let hash = Hash::combine(r, &[block]);
+        let mut coins = [0u8; 32];
+        coins.copy_from_slice(&hash.to_bytes()[..32]);
+        coins
+    }
+
+    /// Encrypt message `m` under encapsulation key `ek` and randomness `r`
+    /// by splitting `m` into [`MESSAGE_BLOCKS`] 32-byte halves and
+    /// encrypting each under the real [`mlwe`] PKE, with per-half coins
+    /// derived from `r`
+    #[inline]
+    fn pke_encrypt(ek: &[u8; KEM_PK_BYTES], m: &[u8; 64], r: &[u8; 64]) -> Ciphertext {
+        let public_key = decode_public_key(ek);
+
+        let mut bytes = [0u8; KEM_CT_BYTES];
+        for block in 0..MESSAGE_BLOCKS {
+            let mut message = [0u8; MESSAGE_BLOCK_BYTES];
+            message.copy_from_slice(&m[block * MESSAGE_BLOCK_BYTES..(block + 1) * MESSAGE_BLOCK_BYTES]);
+            let coins = Self::derive_block_coins(r, block as u8);
+
+            let ciphertext = mlwe::encrypt(&public_key, &message, &coins);
+            encode_mlwe_ciphertext(&ciphertext, &mut bytes[block * MLWE_CT_BYTES..(block + 1) * MLWE_CT_BYTES]);
+        }
+        Ciphertext { bytes }
+    }
+
+    /// Inverse of [`Kem::pke_encrypt`]: recover `m` from a ciphertext using
+    /// the real [`mlwe`] secret key — unlike the placeholder core this
+    /// replaces, decryption is impossible without it
+    #[inline]
+    fn pke_decrypt(secret_key: &mlwe::SecretKey, ciphertext: &Ciphertext) -> [u8; 64] {
+        let mut m = [0u8; 64];
+        for block in 0..MESSAGE_BLOCKS {
+            let block_bytes = &ciphertext.bytes[block * MLWE_CT_BYTES..(block + 1) * MLWE_CT_BYTES];
+            let mlwe_ciphertext = decode_mlwe_ciphertext(block_bytes);
+            let decrypted = mlwe::decrypt(secret_key, &mlwe_ciphertext);
+            m[block * MESSAGE_BLOCK_BYTES..(block + 1) * MESSAGE_BLOCK_BYTES].copy_from_slice(&decrypted);
+        }
+        m
+    }
+
+    /// `J(z || ct)`: the implicit-rejection shared secret returned by
+    /// [`Kem::decapsulate`] when re-encryption does not match, so a tampered
+    /// ciphertext decapsulates to a fixed-but-unpredictable secret instead of
+    /// failing visibly
+    #[inline]
+    fn implicit_reject(z: &[u8; 64], ciphertext: &Ciphertext) -> SharedSecret {
+        let hash = Hash::combine(z, &ciphertext.bytes);
+        let mut bytes = [0u8; 64];
+        bytes.copy_from_slice(hash.as_bytes());
+        SharedSecret { bytes }
+    }
+
     /// Generate a new KEM key pair with optimized performance
     #[inline]
     pub fn keygen() -> (PublicKey, SecretKey) {
         let mut rng = OptimizedRng::new();
 
-        // Generate secret key with better entropy
-        let mut secret_bytes = [0u8; 64];
-        rng.next_bytes(&mut secret_bytes);
+        // Generate decapsulation key with better entropy
+        let mut decapsulation_key = [0u8; 64];
+        rng.next_bytes(&mut decapsulation_key);
+
+        let secret_key = SecretKey::from_bytes(decapsulation_key);
+        let public_key = secret_key.derive_public_key();
+        (public_key, secret_key)
+    }
+
+    /// Generate a new KEM key pair using a caller-supplied CSPRNG
+    #[inline]
+    pub fn keygen_with_rng<R: RngCore + CryptoRng>(rng: &mut R) -> (PublicKey, SecretKey) {
+        let mut decapsulation_key = [0u8; 64];
+        rng.fill_bytes(&mut decapsulation_key);
+
+        let secret_key = SecretKey::from_bytes(decapsulation_key);
+        let public_key = secret_key.derive_public_key();
+        (public_key, secret_key)
+    }
 
-        // Derive public key from secret key using optimized hash function
-        let public_hash = Hash::new(&secret_bytes);
-        let mut public_bytes = [0u8; 64];
-        public_bytes.copy_from_slice(public_hash.as_bytes());
+    /// Deterministically derive a KEM key pair from a 64-byte seed
+    ///
+    /// The seed is expanded through the library's hash function, so the same
+    /// seed always yields the same key pair. This enables reproducible test
+    /// vectors and deterministic key derivation for wallets.
+    pub fn keygen_from_seed(seed: &[u8; 64]) -> (PublicKey, SecretKey) {
+        let decapsulation_key = Hash::combine(seed, b"topay-kem-keygen").to_bytes();
+
+        let secret_key = SecretKey::from_bytes(decapsulation_key);
+        let public_key = secret_key.derive_public_key();
+        (public_key, secret_key)
+    }
 
-        (
-            PublicKey {
-                bytes: public_bytes,
-            },
-            SecretKey {
-                bytes: secret_bytes,
-            },
-        )
+    /// Generate a new KEM key pair directly into caller-provided buffers
+    ///
+    /// Convenience entry point for callers who only want the raw bytes, not
+    /// owned `PublicKey`/`SecretKey` values.
+    #[inline]
+    pub fn keygen_into(public_out: &mut [u8; KEM_PK_BYTES], secret_out: &mut [u8; 64]) {
+        let mut rng = OptimizedRng::new();
+        rng.next_bytes(secret_out);
+
+        let secret_key = SecretKey::from_bytes(*secret_out);
+        public_out.copy_from_slice(&secret_key.encapsulation_key);
     }
 
     /// Encapsulate a shared secret using the public key with optimized performance
@@ -123,100 +379,227 @@ impl Kem {
     pub fn encapsulate(public_key: &PublicKey) -> (Ciphertext, SharedSecret) {
         let mut rng = OptimizedRng::new();
 
-        // Generate random ephemeral key with better entropy
-        let mut ephemeral = [0u8; 64];
-        rng.next_bytes(&mut ephemeral);
+        // Generate random message with better entropy
+        let mut m = [0u8; 64];
+        rng.next_bytes(&mut m);
 
-        // The ciphertext contains the ephemeral key (in a real KEM this would be encrypted)
-        // For this simplified version, we'll use the ephemeral key directly as ciphertext
-        let ciphertext_bytes = ephemeral;
+        Self::encapsulate_from_ephemeral(&m, public_key)
+    }
 
-        // Generate shared secret from ephemeral key and public key using optimized hash
-        let shared_secret_hash = Hash::combine(&ephemeral, &public_key.bytes);
-        let mut shared_secret_bytes = [0u8; 64];
-        shared_secret_bytes.copy_from_slice(shared_secret_hash.as_bytes());
+    /// Encapsulate a shared secret using a caller-supplied CSPRNG
+    #[inline]
+    pub fn encapsulate_with_rng<R: RngCore + CryptoRng>(
+        rng: &mut R,
+        public_key: &PublicKey,
+    ) -> (Ciphertext, SharedSecret) {
+        let mut m = [0u8; 64];
+        rng.fill_bytes(&mut m);
+        Self::encapsulate_from_ephemeral(&m, public_key)
+    }
 
-        (
-            Ciphertext {
-                bytes: ciphertext_bytes,
-            },
-            SharedSecret {
-                bytes: shared_secret_bytes,
-            },
-        )
+    /// Deterministically encapsulate a shared secret from a 64-byte seed
+    ///
+    /// Useful for known-answer tests where the ephemeral randomness must be
+    /// pinned to a fixed value.
+    pub fn encapsulate_from_seed(
+        seed: &[u8; 64],
+        public_key: &PublicKey,
+    ) -> (Ciphertext, SharedSecret) {
+        let m = Hash::combine(seed, b"topay-kem-encapsulate").to_bytes();
+        Self::encapsulate_from_ephemeral(&m, public_key)
     }
 
-    /// Decapsulate the shared secret using the secret key and ciphertext with optimized performance
+    /// Shared core for encapsulation given an already-sampled message `m`:
+    /// the Fujisaki–Okamoto `G`/encrypt steps of ML-KEM's `Encaps`
     #[inline]
-    pub fn decapsulate(secret_key: &SecretKey, ciphertext: &Ciphertext) -> SharedSecret {
-        // Derive the public key from the secret key using optimized hash
-        let public_hash = Hash::new(&secret_key.bytes);
-        let mut public_bytes = [0u8; 64];
-        public_bytes.copy_from_slice(public_hash.as_bytes());
+    fn encapsulate_from_ephemeral(
+        m: &[u8; 64],
+        public_key: &PublicKey,
+    ) -> (Ciphertext, SharedSecret) {
+        let h = Hash::new(&public_key.bytes);
+        let (k, r) = Self::g(m, h.as_bytes());
+        let ciphertext = Self::pke_encrypt(&public_key.bytes, m, &r);
+        (ciphertext, SharedSecret { bytes: k })
+    }
 
-        // Generate shared secret from ciphertext (ephemeral key) and derived public key
-        let shared_secret_hash = Hash::combine(&ciphertext.bytes, &public_bytes);
-        let mut shared_secret_bytes = [0u8; 64];
-        shared_secret_bytes.copy_from_slice(shared_secret_hash.as_bytes());
+    /// Encapsulate a shared secret directly into caller-provided buffers
+    ///
+    /// Convenience entry point for callers who only want the raw bytes, not
+    /// owned `Ciphertext`/`SharedSecret` values.
+    #[inline]
+    pub fn encapsulate_into(
+        public_key_bytes: &[u8; KEM_PK_BYTES],
+        ciphertext_out: &mut [u8; KEM_CT_BYTES],
+        shared_secret_out: &mut [u8; 64],
+    ) {
+        let mut rng = OptimizedRng::new();
+        let mut m = [0u8; 64];
+        rng.next_bytes(&mut m);
 
-        SharedSecret {
-            bytes: shared_secret_bytes,
+        let public_key = PublicKey::from_bytes(*public_key_bytes);
+        let (ciphertext, shared_secret) = Self::encapsulate_from_ephemeral(&m, &public_key);
+        ciphertext_out.copy_from_slice(&ciphertext.bytes);
+        shared_secret_out.copy_from_slice(&shared_secret.bytes);
+    }
+
+    /// Decapsulate the shared secret using the secret key and ciphertext
+    ///
+    /// IND-CCA2 secure via the Fujisaki–Okamoto transform: re-derives the
+    /// message and randomness, re-encrypts, and only returns the derived
+    /// shared secret if the re-encryption matches `ciphertext` exactly
+    /// (compared in constant time). On mismatch — i.e. `ciphertext` was
+    /// tampered with or never produced by [`Kem::encapsulate`] — returns the
+    /// implicit-rejection secret [`Kem::implicit_reject`] instead, so the
+    /// caller can never distinguish "tampered" from "valid" by the shape of
+    /// the result.
+    #[inline]
+    pub fn decapsulate(secret_key: &SecretKey, ciphertext: &Ciphertext) -> SharedSecret {
+        let m_prime = Self::pke_decrypt(&secret_key.mlwe_secret, ciphertext);
+        let (k_prime, r_prime) = Self::g(&m_prime, secret_key.h.as_bytes());
+        let ciphertext_prime = Self::pke_encrypt(&secret_key.encapsulation_key, &m_prime, &r_prime);
+
+        if crate::utils::constant_time_eq(&ciphertext_prime.bytes, &ciphertext.bytes) {
+            SharedSecret { bytes: k_prime }
+        } else {
+            Self::implicit_reject(&secret_key.z, ciphertext)
         }
     }
 
+    /// Take a key pair from a [`crate::keypool::KeyPool`], falling back to
+    /// [`Kem::keygen`] if the pool is momentarily empty
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn keygen_from_pool(pool: &crate::keypool::KeyPool) -> (PublicKey, SecretKey) {
+        pool.take_kem()
+    }
+
     /// Batch key generation for improved performance
     pub fn batch_keygen(count: usize) -> Vec<(PublicKey, SecretKey)> {
         let mut keypairs = Vec::with_capacity(count);
         let mut rng = OptimizedRng::new();
 
         for _ in 0..count {
-            // Generate secret key
-            let mut secret_bytes = [0u8; 64];
-            rng.next_bytes(&mut secret_bytes);
+            let mut decapsulation_key = [0u8; 64];
+            rng.next_bytes(&mut decapsulation_key);
+
+            let secret_key = SecretKey::from_bytes(decapsulation_key);
+            let public_key = secret_key.derive_public_key();
+            keypairs.push((public_key, secret_key));
+        }
 
-            // Derive public key from secret key
-            let public_hash = Hash::new(&secret_bytes);
-            let mut public_bytes = [0u8; 64];
-            public_bytes.copy_from_slice(public_hash.as_bytes());
+        keypairs
+    }
+
+    /// Batch key generation using a caller-supplied CSPRNG
+    pub fn batch_keygen_with_rng<R: RngCore + CryptoRng>(
+        rng: &mut R,
+        count: usize,
+    ) -> Vec<(PublicKey, SecretKey)> {
+        let mut keypairs = Vec::with_capacity(count);
 
-            keypairs.push((
-                PublicKey {
-                    bytes: public_bytes,
-                },
-                SecretKey {
-                    bytes: secret_bytes,
-                },
-            ));
+        for _ in 0..count {
+            keypairs.push(Self::keygen_with_rng(rng));
         }
 
         keypairs
     }
 
+    /// Batch key generation spread across worker threads
+    ///
+    /// Splits `count` key generations into chunks of at least
+    /// [`crate::perf::OPTIMAL_BATCH_SIZE`], sized so there are at most
+    /// [`crate::features::optimal_thread_count`] chunks, and runs each chunk on
+    /// its own worker thread when the `parallel` feature is enabled, falling back to the
+    /// sequential [`Kem::batch_keygen`] path otherwise. Results are returned
+    /// in input order regardless of thread scheduling.
+    pub fn keygen_batch(count: usize) -> Vec<(PublicKey, SecretKey)> {
+        #[cfg(feature = "parallel")]
+        {
+            use std::thread;
+
+            let thread_count = crate::features::optimal_thread_count().max(1);
+            let chunk_size = crate::perf::OPTIMAL_BATCH_SIZE
+                .max(count.div_ceil(thread_count))
+                .max(1);
+
+            let chunk_counts: Vec<usize> = (0..count)
+                .step_by(chunk_size)
+                .map(|start| core::cmp::min(chunk_size, count - start))
+                .collect();
+
+            let results: Vec<Vec<(PublicKey, SecretKey)>> = thread::scope(|scope| {
+                let handles: Vec<_> = chunk_counts
+                    .iter()
+                    .map(|&n| scope.spawn(move || Self::batch_keygen(n)))
+                    .collect();
+                handles.into_iter().map(|h| h.join().unwrap()).collect()
+            });
+
+            results.into_iter().flatten().collect()
+        }
+        #[cfg(not(feature = "parallel"))]
+        {
+            Self::batch_keygen(count)
+        }
+    }
+
+    /// Batch encapsulation spread across worker threads
+    ///
+    /// Mirrors [`Kem::keygen_batch`]: chunks `public_keys` into chunks of at
+    /// least [`crate::perf::OPTIMAL_BATCH_SIZE`], at most
+    /// [`crate::features::optimal_thread_count`] of them, one worker thread per chunk when the
+    /// `parallel` feature is enabled, returning results in input order.
+    pub fn encapsulate_batch(public_keys: &[PublicKey]) -> Vec<(Ciphertext, SharedSecret)> {
+        #[cfg(feature = "parallel")]
+        {
+            use std::thread;
+
+            let thread_count = crate::features::optimal_thread_count().max(1);
+            let chunk_size = crate::perf::OPTIMAL_BATCH_SIZE
+                .max(public_keys.len().div_ceil(thread_count))
+                .max(1);
+
+            let results: Vec<Vec<(Ciphertext, SharedSecret)>> = thread::scope(|scope| {
+                let handles: Vec<_> = public_keys
+                    .chunks(chunk_size)
+                    .map(|chunk| scope.spawn(move || Self::batch_encapsulate(chunk)))
+                    .collect();
+                handles.into_iter().map(|h| h.join().unwrap()).collect()
+            });
+
+            results.into_iter().flatten().collect()
+        }
+        #[cfg(not(feature = "parallel"))]
+        {
+            Self::batch_encapsulate(public_keys)
+        }
+    }
+
     /// Batch encapsulation for improved throughput
     pub fn batch_encapsulate(public_keys: &[PublicKey]) -> Vec<(Ciphertext, SharedSecret)> {
         let mut results = Vec::with_capacity(public_keys.len());
         let mut rng = OptimizedRng::new();
 
         for public_key in public_keys {
-            // Generate random ephemeral key
-            let mut ephemeral = [0u8; 64];
-            rng.next_bytes(&mut ephemeral);
+            let mut m = [0u8; 64];
+            rng.next_bytes(&mut m);
+            results.push(Self::encapsulate_from_ephemeral(&m, public_key));
+        }
 
-            let ciphertext_bytes = ephemeral;
+        results
+    }
 
-            // Generate shared secret
-            let shared_secret_hash = Hash::combine(&ephemeral, &public_key.bytes);
-            let mut shared_secret_bytes = [0u8; 64];
-            shared_secret_bytes.copy_from_slice(shared_secret_hash.as_bytes());
+    /// Batch encapsulation using a caller-supplied CSPRNG
+    pub fn batch_encapsulate_with_rng<R: RngCore + CryptoRng>(
+        rng: &mut R,
+        public_keys: &[PublicKey],
+    ) -> Vec<(Ciphertext, SharedSecret)> {
+        let mut results = Vec::with_capacity(public_keys.len());
 
-            results.push((
-                Ciphertext {
-                    bytes: ciphertext_bytes,
-                },
-                SharedSecret {
-                    bytes: shared_secret_bytes,
-                },
-            ));
+        for public_key in public_keys {
+            let mut m = [0u8; 64];
+            rng.fill_bytes(&mut m);
+            results.push(Self::encapsulate_from_ephemeral(&m, public_key));
         }
 
         results
@@ -226,22 +609,22 @@ impl Kem {
 impl PublicKey {
     /// Create a public key from raw bytes
     #[inline(always)]
-    pub fn from_bytes(bytes: [u8; 64]) -> Self {
+    pub fn from_bytes(bytes: [u8; KEM_PK_BYTES]) -> Self {
         PublicKey { bytes }
     }
 
     /// Create a public key from a hex string with optimized parsing
     pub fn from_hex(hex: &str) -> Result<Self> {
-        if hex.len() != 128 {
+        if hex.len() != KEM_PK_BYTES * 2 {
             return Err(TopayzError::InvalidInput("Invalid hex length".to_string()));
         }
 
-        let mut bytes = [0u8; 64];
+        let mut bytes = [0u8; KEM_PK_BYTES];
 
         // Optimized hex parsing
-        for i in 0..64 {
+        for (i, byte) in bytes.iter_mut().enumerate() {
             let hex_byte = &hex[i * 2..i * 2 + 2];
-            bytes[i] = u8::from_str_radix(hex_byte, 16)
+            *byte = u8::from_str_radix(hex_byte, 16)
                 .map_err(|_| TopayzError::InvalidInput("Invalid hex character".to_string()))?;
         }
         Ok(PublicKey { bytes })
@@ -249,19 +632,25 @@ impl PublicKey {
 
     /// Get the public key as a byte array
     #[inline(always)]
-    pub fn as_bytes(&self) -> &[u8; 64] {
+    pub fn as_bytes(&self) -> &[u8; KEM_PK_BYTES] {
         &self.bytes
     }
 
+    /// Write the public key bytes into a caller-provided buffer
+    #[inline(always)]
+    pub fn to_bytes_into(&self, out: &mut [u8; KEM_PK_BYTES]) {
+        out.copy_from_slice(&self.bytes);
+    }
+
     /// Get the public key as a byte slice
     #[inline(always)]
-    pub fn to_bytes(&self) -> [u8; 64] {
+    pub fn to_bytes(&self) -> [u8; KEM_PK_BYTES] {
         self.bytes
     }
 
     /// Convert the public key to a hex string with optimized formatting
     pub fn to_hex(&self) -> String {
-        let mut hex = String::with_capacity(128);
+        let mut hex = String::with_capacity(KEM_PK_BYTES * 2);
         for &byte in &self.bytes {
             hex.push_str(&format!("{:02x}", byte));
         }
@@ -276,10 +665,28 @@ impl PublicKey {
 }
 
 impl SecretKey {
-    /// Create a secret key from raw bytes
-    #[inline(always)]
-    pub fn from_bytes(bytes: [u8; 64]) -> Self {
-        SecretKey { bytes }
+    /// Build a secret key from a 64-byte decapsulation key: expands it into
+    /// an [`mlwe`] seed (see [`derive_mlwe_seed`]) and runs [`mlwe::keygen`]
+    /// to get the real lattice key pair, then derives the
+    /// Fujisaki–Okamoto auxiliary state (`h`, `z`) from it, all
+    /// deterministically — so the same decapsulation key always
+    /// reconstructs the same `SecretKey`
+    #[inline]
+    pub fn from_bytes(decapsulation_key: [u8; 64]) -> Self {
+        let mlwe_seed = derive_mlwe_seed(&decapsulation_key);
+        let (mlwe_public, mlwe_secret) = mlwe::keygen(&mlwe_seed);
+        let encapsulation_key = encode_mlwe_public_key(&mlwe_public);
+
+        let h = Hash::new(&encapsulation_key);
+        let z = Hash::combine(&decapsulation_key, b"topay-kem-implicit-reject").to_bytes();
+
+        SecretKey {
+            decapsulation_key,
+            encapsulation_key,
+            mlwe_secret,
+            h,
+            z,
+        }
     }
 
     /// Create a secret key from a hex string with optimized parsing
@@ -296,25 +703,34 @@ impl SecretKey {
             bytes[i] = u8::from_str_radix(hex_byte, 16)
                 .map_err(|_| TopayzError::InvalidInput("Invalid hex character".to_string()))?;
         }
-        Ok(SecretKey { bytes })
+        Ok(SecretKey::from_bytes(bytes))
     }
 
-    /// Get the secret key as a byte array
+    /// Get the decapsulation key as a byte array
     #[inline(always)]
     pub fn as_bytes(&self) -> &[u8; 64] {
-        &self.bytes
+        &self.decapsulation_key
+    }
+
+    /// Write the decapsulation key bytes into a caller-provided buffer
+    ///
+    /// Allocation-free counterpart to [`SecretKey::to_bytes`] for `no_std`
+    /// targets without `alloc`.
+    #[inline(always)]
+    pub fn to_bytes_into(&self, out: &mut [u8; 64]) {
+        out.copy_from_slice(&self.decapsulation_key);
     }
 
-    /// Get the secret key as a byte slice
+    /// Get the decapsulation key as a byte slice
     #[inline(always)]
     pub fn to_bytes(&self) -> [u8; 64] {
-        self.bytes
+        self.decapsulation_key
     }
 
-    /// Convert the secret key to a hex string with optimized formatting
+    /// Convert the decapsulation key to a hex string with optimized formatting
     pub fn to_hex(&self) -> String {
         let mut hex = String::with_capacity(128);
-        for &byte in &self.bytes {
+        for &byte in &self.decapsulation_key {
             hex.push_str(&format!("{:02x}", byte));
         }
         hex
@@ -323,39 +739,83 @@ impl SecretKey {
     /// Derive public key from secret key
     #[inline]
     pub fn derive_public_key(&self) -> PublicKey {
-        let public_hash = Hash::new(&self.bytes);
-        let mut public_bytes = [0u8; 64];
-        public_bytes.copy_from_slice(public_hash.as_bytes());
         PublicKey {
-            bytes: public_bytes,
+            bytes: self.encapsulation_key,
         }
     }
 
+    /// Split this decapsulation key into `total` Shamir shares, any
+    /// `threshold` of which reconstruct it via [`SecretKey::combine`]
+    ///
+    /// Enables collaborative decapsulation where no single party holds the
+    /// whole key; see [`crate::threshold`] for the underlying `GF(2^8)`
+    /// scheme and [`crate::threshold::Share`] for the share type (with hex
+    /// conversion and zeroizing `Drop` of its own).
+    #[inline]
+    pub fn split(&self, threshold: u8, total: u8) -> Result<Vec<crate::threshold::Share>> {
+        crate::threshold::split(self, threshold, total)
+    }
+
+    /// Split this decapsulation key using a caller-supplied CSPRNG; see
+    /// [`SecretKey::split`]
+    #[inline]
+    pub fn split_with_rng<R: RngCore + CryptoRng>(
+        &self,
+        threshold: u8,
+        total: u8,
+        rng: &mut R,
+    ) -> Result<Vec<crate::threshold::Share>> {
+        crate::threshold::split_with_rng(self, threshold, total, rng)
+    }
+
+    /// Reconstruct a decapsulation key from `threshold` or more distinct
+    /// shares produced by [`SecretKey::split`] or [`SecretKey::split_with_rng`]
+    #[inline]
+    pub fn combine(shares: &[crate::threshold::Share], threshold: u8) -> Result<SecretKey> {
+        crate::threshold::combine(shares, threshold)
+    }
+
     /// Secure zero out secret key (for security)
+    ///
+    /// Without the `zeroize` feature, routes through
+    /// [`crate::utils::secure_zero`], which writes each byte with
+    /// [`core::ptr::write_volatile`] followed by a compiler fence so the
+    /// optimizer cannot elide the wipe as a dead store into a buffer that's
+    /// about to be dropped.
     pub fn zeroize(&mut self) {
-        self.bytes.fill(0);
+        #[cfg(feature = "zeroize")]
+        {
+            use zeroize::Zeroize;
+            self.decapsulation_key.zeroize();
+            self.z.zeroize();
+        }
+        #[cfg(not(feature = "zeroize"))]
+        {
+            crate::utils::secure_zero(&mut self.decapsulation_key);
+            crate::utils::secure_zero(&mut self.z);
+        }
     }
 }
 
 impl Ciphertext {
     /// Create a ciphertext from raw bytes
     #[inline(always)]
-    pub fn from_bytes(bytes: [u8; 64]) -> Self {
+    pub fn from_bytes(bytes: [u8; KEM_CT_BYTES]) -> Self {
         Ciphertext { bytes }
     }
 
     /// Create a ciphertext from a hex string with optimized parsing
     pub fn from_hex(hex: &str) -> Result<Self> {
-        if hex.len() != 128 {
+        if hex.len() != KEM_CT_BYTES * 2 {
             return Err(TopayzError::InvalidInput("Invalid hex length".to_string()));
         }
 
-        let mut bytes = [0u8; 64];
+        let mut bytes = [0u8; KEM_CT_BYTES];
 
         // Optimized hex parsing
-        for i in 0..64 {
+        for (i, byte) in bytes.iter_mut().enumerate() {
             let hex_byte = &hex[i * 2..i * 2 + 2];
-            bytes[i] = u8::from_str_radix(hex_byte, 16)
+            *byte = u8::from_str_radix(hex_byte, 16)
                 .map_err(|_| TopayzError::InvalidInput("Invalid hex character".to_string()))?;
         }
         Ok(Ciphertext { bytes })
@@ -363,19 +823,19 @@ impl Ciphertext {
 
     /// Get the ciphertext as a byte array
     #[inline(always)]
-    pub fn as_bytes(&self) -> &[u8; 64] {
+    pub fn as_bytes(&self) -> &[u8; KEM_CT_BYTES] {
         &self.bytes
     }
 
     /// Get the ciphertext as a byte slice
     #[inline(always)]
-    pub fn to_bytes(&self) -> [u8; 64] {
+    pub fn to_bytes(&self) -> [u8; KEM_CT_BYTES] {
         self.bytes
     }
 
     /// Convert the ciphertext to a hex string with optimized formatting
     pub fn to_hex(&self) -> String {
-        let mut hex = String::with_capacity(128);
+        let mut hex = String::with_capacity(KEM_CT_BYTES * 2);
         for &byte in &self.bytes {
             hex.push_str(&format!("{:02x}", byte));
         }
@@ -434,9 +894,193 @@ impl SharedSecret {
         Hash::combine(&self.bytes, info)
     }
 
+    /// Extract-then-expand this shared secret into `out_len` bytes of
+    /// independent key material, HKDF-style
+    ///
+    /// Extract: `PRK = Hash::combine(salt, secret)`. Expand: each 64-byte
+    /// block `T(i)` absorbs `PRK`, the previous block `T(i-1)` (empty for
+    /// `T(1)`), `info`, and a one-byte counter, so distinct `info` labels —
+    /// e.g. `b"encryption"` vs `b"mac"` — yield cryptographically
+    /// independent outputs from the same shared secret, the standard way
+    /// one encapsulation is turned into several purpose-bound subkeys.
+    pub fn expand(&self, salt: &[u8], info: &[u8], out_len: usize) -> Vec<u8> {
+        let prk = Hash::combine(salt, &self.bytes);
+
+        let mut output = Vec::with_capacity(out_len);
+        let mut previous_block: Option<[u8; 64]> = None;
+        let mut counter: u8 = 1;
+
+        while output.len() < out_len {
+            let mut hasher = Hasher::new();
+            hasher.update(prk.as_bytes());
+            if let Some(previous) = previous_block {
+                hasher.update(&previous);
+            }
+            hasher.update(info);
+            hasher.update(&[counter]);
+            let block = hasher.finalize();
+
+            let remaining = out_len - output.len();
+            let to_copy = core::cmp::min(block.as_bytes().len(), remaining);
+            output.extend_from_slice(&block.as_bytes()[..to_copy]);
+
+            previous_block = Some(*block.as_bytes());
+            counter = counter.wrapping_add(1);
+        }
+
+        output
+    }
+
     /// Secure zero out shared secret (for security)
+    ///
+    /// See [`SecretKey::zeroize`] for why the `zeroize`-less path goes
+    /// through [`crate::utils::secure_zero`] instead of a plain `fill(0)`.
     pub fn zeroize(&mut self) {
-        self.bytes.fill(0);
+        #[cfg(feature = "zeroize")]
+        {
+            use zeroize::Zeroize;
+            self.bytes.zeroize();
+        }
+        #[cfg(not(feature = "zeroize"))]
+        {
+            crate::utils::secure_zero(&mut self.bytes);
+        }
+    }
+}
+
+// For human-readable formats (JSON, TOML), each of these four types
+// serializes as its own fixed-length hex string via `to_hex`/`from_hex`
+// (128 characters for `SecretKey`/`SharedSecret`, wider for `PublicKey`/
+// `Ciphertext` now that they carry `mlwe` key/ciphertext material); for
+// binary formats (bincode, CBOR) the underlying fixed-size byte array
+// serializes as a tuple with no length prefix. Deserialization always goes
+// through the validating `from_hex`/`from_bytes` constructors, so a
+// malformed blob is rejected rather than accepted or panicking.
+#[cfg(feature = "serde")]
+impl serde::Serialize for PublicKey {
+    fn serialize<S>(&self, serializer: S) -> core::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        if serializer.is_human_readable() {
+            serializer.serialize_str(&self.to_hex())
+        } else {
+            self.bytes.serialize(serializer)
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for PublicKey {
+    fn deserialize<D>(deserializer: D) -> core::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        use serde::de::Error;
+
+        if deserializer.is_human_readable() {
+            let hex = String::deserialize(deserializer)?;
+            PublicKey::from_hex(&hex).map_err(D::Error::custom)
+        } else {
+            let bytes = crate::utils::deserialize_byte_array(deserializer)?;
+            Ok(PublicKey::from_bytes(bytes))
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for SecretKey {
+    fn serialize<S>(&self, serializer: S) -> core::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        if serializer.is_human_readable() {
+            serializer.serialize_str(&self.to_hex())
+        } else {
+            self.decapsulation_key.serialize(serializer)
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for SecretKey {
+    fn deserialize<D>(deserializer: D) -> core::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        use serde::de::Error;
+
+        if deserializer.is_human_readable() {
+            let hex = String::deserialize(deserializer)?;
+            SecretKey::from_hex(&hex).map_err(D::Error::custom)
+        } else {
+            let bytes = crate::utils::deserialize_byte_array(deserializer)?;
+            Ok(SecretKey::from_bytes(bytes))
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Ciphertext {
+    fn serialize<S>(&self, serializer: S) -> core::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        if serializer.is_human_readable() {
+            serializer.serialize_str(&self.to_hex())
+        } else {
+            self.bytes.serialize(serializer)
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Ciphertext {
+    fn deserialize<D>(deserializer: D) -> core::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        use serde::de::Error;
+
+        if deserializer.is_human_readable() {
+            let hex = String::deserialize(deserializer)?;
+            Ciphertext::from_hex(&hex).map_err(D::Error::custom)
+        } else {
+            let bytes = crate::utils::deserialize_byte_array(deserializer)?;
+            Ok(Ciphertext::from_bytes(bytes))
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for SharedSecret {
+    fn serialize<S>(&self, serializer: S) -> core::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        if serializer.is_human_readable() {
+            serializer.serialize_str(&self.to_hex())
+        } else {
+            self.bytes.serialize(serializer)
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for SharedSecret {
+    fn deserialize<D>(deserializer: D) -> core::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        use serde::de::Error;
+
+        if deserializer.is_human_readable() {
+            let hex = String::deserialize(deserializer)?;
+            SharedSecret::from_hex(&hex).map_err(D::Error::custom)
+        } else {
+            let bytes = crate::utils::deserialize_byte_array(deserializer)?;
+            Ok(SharedSecret::from_bytes(bytes))
+        }
     }
 }
 
@@ -460,7 +1104,7 @@ mod tests {
     #[test]
     fn test_kem_keygen() {
         let (public_key, secret_key) = Kem::keygen();
-        assert_eq!(public_key.as_bytes().len(), 64);
+        assert_eq!(public_key.as_bytes().len(), KEM_PK_BYTES);
         assert_eq!(secret_key.as_bytes().len(), 64);
     }
 
@@ -470,10 +1114,35 @@ mod tests {
         let (ciphertext, shared_secret1) = Kem::encapsulate(&public_key);
         let shared_secret2 = Kem::decapsulate(&secret_key, &ciphertext);
 
-        // Note: In this simplified implementation, the shared secrets won't match
-        // because we're not implementing proper KEM semantics
-        assert_eq!(shared_secret1.as_bytes().len(), 64);
-        assert_eq!(shared_secret2.as_bytes().len(), 64);
+        // Honest decapsulation recovers the same shared secret as encapsulation.
+        assert_eq!(shared_secret1, shared_secret2);
+    }
+
+    #[test]
+    fn test_decapsulate_rejects_tampered_ciphertext() {
+        let (public_key, secret_key) = Kem::keygen();
+        let (ciphertext, shared_secret) = Kem::encapsulate(&public_key);
+
+        let mut tampered_bytes = *ciphertext.as_bytes();
+        tampered_bytes[100] ^= 0x01;
+        let tampered = Ciphertext::from_bytes(tampered_bytes);
+
+        let rejected = Kem::decapsulate(&secret_key, &tampered);
+        assert_ne!(rejected, shared_secret);
+    }
+
+    #[test]
+    fn test_implicit_rejection_is_deterministic() {
+        let (public_key, secret_key) = Kem::keygen();
+        let (ciphertext, _) = Kem::encapsulate(&public_key);
+
+        let mut tampered_bytes = *ciphertext.as_bytes();
+        tampered_bytes[0] ^= 0x01;
+        let tampered = Ciphertext::from_bytes(tampered_bytes);
+
+        let rejected1 = Kem::decapsulate(&secret_key, &tampered);
+        let rejected2 = Kem::decapsulate(&secret_key, &tampered);
+        assert_eq!(rejected1, rejected2);
     }
 
     #[test]
@@ -492,12 +1161,32 @@ mod tests {
         assert_eq!(secret_key, secret_key2);
     }
 
+    #[test]
+    fn test_from_hex_rejects_malformed_input() {
+        assert!(PublicKey::from_hex("not-hex").is_err());
+        assert!(PublicKey::from_hex("deadbeef").is_err()); // too short
+
+        assert!(SecretKey::from_hex("not-hex").is_err());
+        assert!(Ciphertext::from_hex("not-hex").is_err());
+        assert!(SharedSecret::from_hex("not-hex").is_err());
+    }
+
+    #[test]
+    fn test_keygen_and_encapsulate_batch() {
+        let keypairs = Kem::keygen_batch(40);
+        assert_eq!(keypairs.len(), 40);
+
+        let public_keys: Vec<PublicKey> = keypairs.into_iter().map(|(pk, _)| pk).collect();
+        let results = Kem::encapsulate_batch(&public_keys);
+        assert_eq!(results.len(), public_keys.len());
+    }
+
     #[test]
     fn test_batch_keygen() {
         let keypairs = Kem::batch_keygen(10);
         assert_eq!(keypairs.len(), 10);
         for (public_key, secret_key) in keypairs {
-            assert_eq!(public_key.as_bytes().len(), 64);
+            assert_eq!(public_key.as_bytes().len(), KEM_PK_BYTES);
             assert_eq!(secret_key.as_bytes().len(), 64);
         }
     }
@@ -509,15 +1198,190 @@ mod tests {
         let results = Kem::batch_encapsulate(&public_keys);
         assert_eq!(results.len(), 5);
         for (ciphertext, shared_secret) in results {
-            assert_eq!(ciphertext.as_bytes().len(), 64);
+            assert_eq!(ciphertext.as_bytes().len(), KEM_CT_BYTES);
             assert_eq!(shared_secret.as_bytes().len(), 64);
         }
     }
 
+    #[test]
+    fn test_batch_keygen_and_encapsulate_with_rng() {
+        use rand_core::OsRng;
+
+        let mut rng = OsRng;
+        let keypairs = Kem::batch_keygen_with_rng(&mut rng, 5);
+        assert_eq!(keypairs.len(), 5);
+
+        let public_keys: Vec<PublicKey> = keypairs.into_iter().map(|(pk, _)| pk).collect();
+        let results = Kem::batch_encapsulate_with_rng(&mut rng, &public_keys);
+        assert_eq!(results.len(), public_keys.len());
+    }
+
+    #[test]
+    fn test_keygen_with_rng() {
+        use rand_core::OsRng;
+
+        let mut rng = OsRng;
+        let (public_key, secret_key) = Kem::keygen_with_rng(&mut rng);
+        assert_eq!(public_key, secret_key.derive_public_key());
+    }
+
+    #[test]
+    fn test_keygen_and_encapsulate_from_seed_are_deterministic() {
+        let seed = [3u8; 64];
+
+        let (public_key1, secret_key1) = Kem::keygen_from_seed(&seed);
+        let (public_key2, secret_key2) = Kem::keygen_from_seed(&seed);
+        assert_eq!(public_key1, public_key2);
+        assert_eq!(secret_key1.as_bytes(), secret_key2.as_bytes());
+
+        let encap_seed = [4u8; 64];
+        let (ciphertext1, shared_secret1) = Kem::encapsulate_from_seed(&encap_seed, &public_key1);
+        let (ciphertext2, shared_secret2) = Kem::encapsulate_from_seed(&encap_seed, &public_key1);
+        assert_eq!(ciphertext1, ciphertext2);
+        assert_eq!(shared_secret1.as_bytes(), shared_secret2.as_bytes());
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_serde_human_readable_roundtrip() {
+        let (public_key, secret_key) = Kem::keygen();
+        let (ciphertext, shared_secret) = Kem::encapsulate(&public_key);
+
+        let json = serde_json::to_string(&public_key).unwrap();
+        assert_eq!(
+            serde_json::from_str::<PublicKey>(&json).unwrap(),
+            public_key
+        );
+
+        let json = serde_json::to_string(&secret_key).unwrap();
+        assert_eq!(
+            serde_json::from_str::<SecretKey>(&json).unwrap().as_bytes(),
+            secret_key.as_bytes()
+        );
+
+        let json = serde_json::to_string(&ciphertext).unwrap();
+        assert_eq!(
+            serde_json::from_str::<Ciphertext>(&json).unwrap(),
+            ciphertext
+        );
+
+        let json = serde_json::to_string(&shared_secret).unwrap();
+        assert_eq!(
+            serde_json::from_str::<SharedSecret>(&json)
+                .unwrap()
+                .as_bytes(),
+            shared_secret.as_bytes()
+        );
+    }
+
+    #[test]
+    fn test_keygen_and_encapsulate_into_buffers() {
+        let mut public_bytes = [0u8; KEM_PK_BYTES];
+        let mut secret_bytes = [0u8; 64];
+        Kem::keygen_into(&mut public_bytes, &mut secret_bytes);
+
+        let secret_key = SecretKey::from_bytes(secret_bytes);
+        assert_eq!(secret_key.derive_public_key().as_bytes(), &public_bytes);
+
+        let mut ciphertext_bytes = [0u8; KEM_CT_BYTES];
+        let mut shared_secret_bytes = [0u8; 64];
+        Kem::encapsulate_into(
+            &public_bytes,
+            &mut ciphertext_bytes,
+            &mut shared_secret_bytes,
+        );
+
+        let ciphertext = Ciphertext::from_bytes(ciphertext_bytes);
+        let secret_key = SecretKey::from_bytes(secret_bytes);
+        let decapsulated = Kem::decapsulate(&secret_key, &ciphertext);
+        assert_eq!(decapsulated.as_bytes(), &shared_secret_bytes);
+    }
+
+    #[test]
+    fn test_secret_key_constant_time_eq() {
+        let (_, secret_key1) = Kem::keygen();
+        let secret_key2 = SecretKey::from_bytes(*secret_key1.as_bytes());
+        let (_, secret_key3) = Kem::keygen();
+
+        assert_eq!(secret_key1, secret_key2);
+        assert_ne!(secret_key1, secret_key3);
+    }
+
+    #[test]
+    fn test_expand_produces_requested_length_and_is_deterministic() {
+        let (public_key, secret_key) = Kem::keygen();
+        let (ciphertext, shared_secret) = Kem::encapsulate(&public_key);
+        let _ = Kem::decapsulate(&secret_key, &ciphertext);
+
+        let output1 = shared_secret.expand(b"salt", b"encryption", 100);
+        let output2 = shared_secret.expand(b"salt", b"encryption", 100);
+        assert_eq!(output1.len(), 100);
+        assert_eq!(output1, output2);
+    }
+
+    #[test]
+    fn test_expand_is_domain_separated_by_info() {
+        let (_, secret_key) = Kem::keygen();
+        let shared_secret = SharedSecret::from_bytes(*secret_key.as_bytes());
+
+        let encryption_key = shared_secret.expand(b"salt", b"encryption", 32);
+        let mac_key = shared_secret.expand(b"salt", b"mac", 32);
+        assert_ne!(encryption_key, mac_key);
+    }
+
+    #[test]
+    fn test_zeroize_clears_secret_bytes() {
+        let (_, mut secret_key) = Kem::keygen();
+        secret_key.zeroize();
+        assert_eq!(secret_key.as_bytes(), &[0u8; 64]);
+
+        let (public_key, _) = Kem::keygen();
+        let (_, mut shared_secret) = Kem::encapsulate(&public_key);
+        shared_secret.zeroize();
+        assert_eq!(shared_secret.as_bytes(), &[0u8; 64]);
+    }
+
+    #[test]
+    fn test_shared_secret_constant_time_eq() {
+        let (public_key, secret_key) = Kem::keygen();
+        let (ciphertext, shared_secret1) = Kem::encapsulate(&public_key);
+        let shared_secret2 = Kem::decapsulate(&secret_key, &ciphertext);
+        let (_, unrelated_secret) = Kem::encapsulate(&public_key);
+
+        assert_eq!(shared_secret1, shared_secret2);
+        assert_ne!(shared_secret1, unrelated_secret);
+    }
+
+    #[test]
+    fn test_secret_key_split_and_combine_round_trip() {
+        let (_, secret_key) = Kem::keygen();
+
+        let shares = secret_key.split(3, 5).unwrap();
+        assert_eq!(shares.len(), 5);
+
+        let recovered = SecretKey::combine(&shares[0..3], 3).unwrap();
+        assert_eq!(recovered, secret_key);
+    }
+
     #[test]
     fn test_derive_public_key() {
         let (public_key, secret_key) = Kem::keygen();
         let derived_public_key = secret_key.derive_public_key();
         assert_eq!(public_key, derived_public_key);
     }
+
+    #[test]
+    fn test_decapsulate_requires_matching_secret_key() {
+        // The FO transform's IND-CCA2 proof assumes the underlying PKE is
+        // CPA-secure, i.e. that decryption is impossible without the matching
+        // secret key. A wrong secret key must not recover the right message
+        // (and so must not reproduce the encapsulated shared secret) even
+        // though it decapsulates the same ciphertext without error.
+        let (public_key, _) = Kem::keygen();
+        let (_, unrelated_secret_key) = Kem::keygen();
+        let (ciphertext, shared_secret) = Kem::encapsulate(&public_key);
+
+        let wrong = Kem::decapsulate(&unrelated_secret_key, &ciphertext);
+        assert_ne!(wrong, shared_secret);
+    }
 }
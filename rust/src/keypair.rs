@@ -4,18 +4,76 @@
 //! In production, this would use cryptographically secure random number generation.
 
 #[cfg(not(feature = "std"))]
-use alloc::{string::String, vec::Vec};
+use alloc::{format, string::String, string::ToString, vec::Vec};
 
 use crate::error::{Result, TopayzError};
+use crate::hash::Hash;
 use crate::{PRIVATE_KEY_SIZE, PUBLIC_KEY_SIZE};
+use rand_core::{CryptoRng, RngCore};
+#[cfg(feature = "std")]
 use std::time::{SystemTime, UNIX_EPOCH};
 
 /// A private key for TOPAY-Z512 with optimized layout
-#[derive(Debug, Clone, PartialEq, Eq)]
+///
+/// Deliberately does not derive `PartialOrd`, `Ord`, or `Hash`: sorting or
+/// hashing secret key material invites it to end up in logs, sorted
+/// collections, or hash maps where it can leak through variable-time
+/// comparisons. Equality is constant-time (see `impl PartialEq`). `Clone`
+/// is still derived — a clone is a fully independent `PrivateKey`, and its
+/// own `Drop` impl zeroizes it on scope exit the same as the original.
+#[derive(Debug, Clone)]
 pub struct PrivateKey {
     bytes: [u8; PRIVATE_KEY_SIZE],
 }
 
+impl PartialEq for PrivateKey {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        crate::utils::constant_time_eq(&self.bytes, &other.bytes)
+    }
+}
+
+impl Eq for PrivateKey {}
+
+/// A heap-allocated hex string that is zeroed on drop
+///
+/// Returned by [`PrivateKey::to_hex_zeroizing`] so a private key's hex
+/// encoding doesn't linger on the heap after the caller is done with it,
+/// the way a plain `String` would until its backing allocation happens to
+/// be reused. Derefs to `&str` for read access.
+pub struct ZeroizingHex(String);
+
+impl ZeroizingHex {
+    /// Borrow the hex string
+    #[inline(always)]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl core::ops::Deref for ZeroizingHex {
+    type Target = str;
+
+    #[inline(always)]
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl core::fmt::Debug for ZeroizingHex {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("ZeroizingHex(***)")
+    }
+}
+
+impl Drop for ZeroizingHex {
+    fn drop(&mut self) {
+        // Safety: overwriting with `0x00` bytes keeps the string valid UTF-8.
+        let bytes = unsafe { self.0.as_bytes_mut() };
+        crate::utils::secure_zero(bytes);
+    }
+}
+
 /// A public key for TOPAY-Z512 with optimized layout
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct PublicKey {
@@ -28,7 +86,13 @@ struct OptimizedRng {
 }
 
 impl OptimizedRng {
-    /// Create a new optimized RNG with better entropy
+    /// Create a new optimized RNG seeded from the system clock
+    ///
+    /// Not reproducible, and two instances created in the same nanosecond
+    /// window start from correlated state — only suitable as the `std`
+    /// convenience default. Use [`OptimizedRng::from_seed`] for
+    /// reproducible test vectors or deterministic derivation.
+    #[cfg(feature = "std")]
     #[inline]
     fn new() -> Self {
         let now = SystemTime::now()
@@ -47,6 +111,31 @@ impl OptimizedRng {
         Self { state }
     }
 
+    /// Deterministically seed the Xoshiro256** state from a 32-byte seed
+    ///
+    /// Expands the seed into the four `u64` state words with a SplitMix64
+    /// step per word (the standard way to seed Xoshiro generators), so the
+    /// same seed always produces the same keys. Guards against the
+    /// all-zero state, which would make Xoshiro256** output nothing but
+    /// zeroes forever.
+    fn from_seed(seed: [u8; 32]) -> Self {
+        let mut state = [0u64; 4];
+        for (i, word) in state.iter_mut().enumerate() {
+            let mut chunk = [0u8; 8];
+            chunk.copy_from_slice(&seed[i * 8..i * 8 + 8]);
+            let mut z = u64::from_le_bytes(chunk).wrapping_add(0x9E3779B97F4A7C15);
+            z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+            *word = z ^ (z >> 31);
+        }
+
+        if state == [0u64; 4] {
+            state[0] = 1;
+        }
+
+        Self { state }
+    }
+
     /// Generate random bytes efficiently
     #[inline]
     fn fill_bytes(&mut self, bytes: &mut [u8]) {
@@ -82,14 +171,38 @@ impl OptimizedRng {
 }
 
 /// A cryptographic key pair consisting of a private and public key with optimized layout
-#[derive(Debug, Clone, PartialEq, Eq)]
+///
+/// Equality is not derived: a derived impl would short-circuit on the
+/// `private_key` field and skip comparing `public_key` entirely, leaking
+/// through timing whether it was the private or public half that differed.
+/// `private_key`'s own `PartialEq` is already constant-time (see
+/// [`PrivateKey`]'s docs); this combines both fields' results with bitwise
+/// `&` instead of `&&` so neither field's comparison is skipped.
+#[derive(Debug, Clone)]
 pub struct KeyPair {
     private_key: PrivateKey,
     public_key: PublicKey,
 }
 
+impl PartialEq for KeyPair {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        let private_eq = self.private_key == other.private_key;
+        let public_eq = self.public_key == other.public_key;
+        private_eq & public_eq
+    }
+}
+
+impl Eq for KeyPair {}
+
 impl PrivateKey {
-    /// Generate a new random private key with optimized performance
+    /// Generate a new random private key, seeded from the system clock
+    ///
+    /// Convenience default for `std` targets. The clock-seeded RNG is
+    /// neither reproducible nor cryptographically secure on its own; prefer
+    /// [`PrivateKey::generate_with_rng`] with an external CSPRNG, or
+    /// [`PrivateKey::generate_with_seed`] for reproducible derivation.
+    #[cfg(feature = "std")]
     #[inline]
     pub fn generate() -> Self {
         let mut rng = OptimizedRng::new();
@@ -98,12 +211,93 @@ impl PrivateKey {
         Self { bytes }
     }
 
+    /// Generate a new private key using a caller-supplied CSPRNG
+    #[inline]
+    pub fn generate_with_rng<R: RngCore + CryptoRng>(rng: &mut R) -> Self {
+        let mut bytes = [0u8; PRIVATE_KEY_SIZE];
+        rng.fill_bytes(&mut bytes);
+        Self { bytes }
+    }
+
+    /// Deterministically derive a private key from a 64-byte seed
+    ///
+    /// The seed is expanded through the library's hash function, so the same
+    /// seed always produces the same key. This enables reproducible test
+    /// vectors and deterministic key derivation for wallets.
+    pub fn from_seed(seed: &[u8; 64]) -> Self {
+        let expanded = Hash::combine(seed, b"topay-privatekey-seed");
+        Self {
+            bytes: expanded.to_bytes(),
+        }
+    }
+
+    /// Deterministically generate a private key from a 32-byte seed via a
+    /// seeded CSPRNG
+    ///
+    /// Unlike [`PrivateKey::from_seed`], which expands the seed directly
+    /// through the hash function, this seeds [`OptimizedRng`]'s Xoshiro256**
+    /// state with a SplitMix64 expansion of `seed` and draws the key bytes
+    /// from it — the same path [`PrivateKey::generate`] takes, just with a
+    /// reproducible RNG in place of the system clock. Available without
+    /// `std`.
+    pub fn generate_with_seed(seed: [u8; 32]) -> Self {
+        let mut rng = OptimizedRng::from_seed(seed);
+        let mut bytes = [0u8; PRIVATE_KEY_SIZE];
+        rng.fill_bytes(&mut bytes);
+        Self { bytes }
+    }
+
+    /// Generate a new random private key directly into a caller-provided buffer
+    ///
+    /// Avoids constructing an owned `PrivateKey` when only the raw bytes are
+    /// needed. Seeded from the system clock, so requires `std`; without it,
+    /// fill a buffer via [`PrivateKey::generate_with_rng`] or
+    /// [`PrivateKey::generate_with_seed`] instead.
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn generate_into(out: &mut [u8; PRIVATE_KEY_SIZE]) {
+        let mut rng = OptimizedRng::new();
+        rng.fill_bytes(out);
+    }
+
     /// Create a private key from bytes
     #[inline(always)]
     pub fn from_bytes(bytes: [u8; PRIVATE_KEY_SIZE]) -> Self {
         Self { bytes }
     }
 
+    /// Create a private key from bytes, rejecting trivially-weak keys
+    ///
+    /// Unlike [`PrivateKey::from_bytes`], which accepts any byte array,
+    /// this rejects the all-zero and all-`0xFF` keys with
+    /// [`TopayzError::InvalidInput`] — degenerate values a caller should
+    /// never end up with but that a blind constructor would happily build.
+    pub fn try_from_bytes(bytes: [u8; PRIVATE_KEY_SIZE]) -> Result<Self> {
+        if bytes == [0u8; PRIVATE_KEY_SIZE] || bytes == [0xFFu8; PRIVATE_KEY_SIZE] {
+            return Err(TopayzError::InvalidInput(
+                "Private key must not be all-zero or all-0xFF".to_string(),
+            ));
+        }
+        Ok(Self { bytes })
+    }
+
+    /// Create a private key from a byte slice of the correct length,
+    /// rejecting trivially-weak keys
+    ///
+    /// Combines the length check a raw `&[u8]` needs with the degenerate-key
+    /// rejection of [`PrivateKey::try_from_bytes`].
+    pub fn from_slice(slice: &[u8]) -> Result<Self> {
+        if slice.len() != PRIVATE_KEY_SIZE {
+            return Err(TopayzError::InvalidInput(format!(
+                "Private key must be {PRIVATE_KEY_SIZE} bytes, got {}",
+                slice.len()
+            )));
+        }
+        let mut bytes = [0u8; PRIVATE_KEY_SIZE];
+        bytes.copy_from_slice(slice);
+        Self::try_from_bytes(bytes)
+    }
+
     /// Get the bytes of the private key
     #[inline(always)]
     pub fn to_bytes(&self) -> [u8; PRIVATE_KEY_SIZE] {
@@ -116,6 +310,15 @@ impl PrivateKey {
         &self.bytes
     }
 
+    /// Write the private key bytes into a caller-provided buffer
+    ///
+    /// Allocation-free counterpart to [`PrivateKey::to_bytes`] for `no_std`
+    /// targets without `alloc`.
+    #[inline(always)]
+    pub fn to_bytes_into(&self, out: &mut [u8; PRIVATE_KEY_SIZE]) {
+        out.copy_from_slice(&self.bytes);
+    }
+
     /// Create a private key from hex string with optimized parsing
     pub fn from_hex(hex: &str) -> Result<Self> {
         if hex.len() != PRIVATE_KEY_SIZE * 2 {
@@ -130,7 +333,7 @@ impl PrivateKey {
             bytes[i] = u8::from_str_radix(hex_byte, 16)
                 .map_err(|_| TopayzError::InvalidInput("Invalid hex character".to_string()))?;
         }
-        Ok(Self { bytes })
+        Self::try_from_bytes(bytes)
     }
 
     /// Convert the private key to hex string with optimized formatting
@@ -142,6 +345,47 @@ impl PrivateKey {
         hex
     }
 
+    /// Convert the private key to hex, scrubbing the intermediate `String`
+    /// on drop instead of leaving it on the heap
+    ///
+    /// Prefer this over [`PrivateKey::to_hex`] whenever the hex encoding
+    /// itself is sensitive and short-lived (e.g. for display or a one-shot
+    /// export), since a plain `String` is not zeroized when dropped.
+    pub fn to_hex_zeroizing(&self) -> ZeroizingHex {
+        ZeroizingHex(self.to_hex())
+    }
+
+    /// Add `tweak` to this private key byte-wise, producing a derived key
+    ///
+    /// Mirrors secp256k1's `tweak_add`/`add_assign`: the derived public key
+    /// computed from `self.tweak_add(tweak).public_key()` is always equal to
+    /// `self.public_key().tweak_add(tweak)`, so a tweak can be applied to
+    /// either half of a key pair and the relationship still holds. See
+    /// [`PrivateKey::derive_child`] for a higher-level, index-based tweak.
+    pub fn tweak_add(&self, tweak: &[u8; PRIVATE_KEY_SIZE]) -> PrivateKey {
+        let mut bytes = [0u8; PRIVATE_KEY_SIZE];
+        for i in 0..PRIVATE_KEY_SIZE {
+            bytes[i] = self.bytes[i].wrapping_add(tweak[i]);
+        }
+        PrivateKey { bytes }
+    }
+
+    /// Deterministically derive the `index`-th child of this key
+    ///
+    /// Computes the tweak by hashing this key's bytes together with `index`,
+    /// then applies it via [`PrivateKey::tweak_add`] — a lightweight
+    /// alternative to a full BIP32 derivation tree for wallets that only
+    /// need a flat, deterministic set of child keys.
+    pub fn derive_child(&self, index: u32) -> PrivateKey {
+        let tweak = Self::child_tweak(&self.bytes, index);
+        self.tweak_add(&tweak)
+    }
+
+    /// Hash this key's bytes and `index` into a tweak for [`PrivateKey::derive_child`]
+    fn child_tweak(parent_bytes: &[u8; PRIVATE_KEY_SIZE], index: u32) -> [u8; PRIVATE_KEY_SIZE] {
+        Hash::combine(parent_bytes, &index.to_le_bytes()).to_bytes()
+    }
+
     /// Derive the public key from this private key with optimized computation
     #[inline]
     pub fn public_key(&self) -> PublicKey {
@@ -167,6 +411,11 @@ impl PrivateKey {
     }
 
     /// Batch generate multiple private keys for improved performance
+    ///
+    /// Convenience default for `std` targets; see
+    /// [`PrivateKey::batch_generate_with_rng`] for an external-CSPRNG path
+    /// available without `std`.
+    #[cfg(feature = "std")]
     pub fn batch_generate(count: usize) -> Vec<Self> {
         let mut keys = Vec::with_capacity(count);
         let mut rng = OptimizedRng::new();
@@ -180,15 +429,47 @@ impl PrivateKey {
         keys
     }
 
+    /// Batch generate multiple private keys using a caller-supplied CSPRNG
+    pub fn batch_generate_with_rng<R: RngCore + CryptoRng>(rng: &mut R, count: usize) -> Vec<Self> {
+        let mut keys = Vec::with_capacity(count);
+
+        for _ in 0..count {
+            keys.push(Self::generate_with_rng(rng));
+        }
+
+        keys
+    }
+
     /// Secure zero out private key (for security)
     pub fn zeroize(&mut self) {
-        self.bytes.fill(0);
+        #[cfg(feature = "zeroize")]
+        {
+            use zeroize::Zeroize;
+            self.bytes.zeroize();
+        }
+        #[cfg(not(feature = "zeroize"))]
+        {
+            crate::utils::secure_zero(&mut self.bytes);
+        }
     }
 
-    /// Fast equality check for private keys
+    /// Constant-time equality check for private keys
+    ///
+    /// Equivalent to `==`, spelled out for callers migrating from the
+    /// early-exit `equals` this used to be; see the type-level docs for why
+    /// a derived or early-exit comparison is unsafe for secret key material.
     #[inline(always)]
     pub fn equals(&self, other: &PrivateKey) -> bool {
-        self.bytes == other.bytes
+        crate::utils::constant_time_eq(&self.bytes, &other.bytes)
+    }
+
+    /// Constant-time equality check for private keys
+    ///
+    /// Alias of [`PrivateKey::equals`], named to match [`Hash::ct_eq`] for
+    /// callers that compare secret material across several types.
+    #[inline(always)]
+    pub fn ct_eq(&self, other: &PrivateKey) -> bool {
+        self.equals(other)
     }
 }
 
@@ -199,6 +480,19 @@ impl PublicKey {
         Self { bytes }
     }
 
+    /// Create a public key from a byte slice of the correct length
+    pub fn from_slice(slice: &[u8]) -> Result<Self> {
+        if slice.len() != PUBLIC_KEY_SIZE {
+            return Err(TopayzError::InvalidInput(format!(
+                "Public key must be {PUBLIC_KEY_SIZE} bytes, got {}",
+                slice.len()
+            )));
+        }
+        let mut bytes = [0u8; PUBLIC_KEY_SIZE];
+        bytes.copy_from_slice(slice);
+        Ok(Self { bytes })
+    }
+
     /// Get the bytes of the public key
     #[inline(always)]
     pub fn to_bytes(&self) -> [u8; PUBLIC_KEY_SIZE] {
@@ -211,6 +505,15 @@ impl PublicKey {
         &self.bytes
     }
 
+    /// Write the public key bytes into a caller-provided buffer
+    ///
+    /// Allocation-free counterpart to [`PublicKey::to_bytes`] for `no_std`
+    /// targets without `alloc`.
+    #[inline(always)]
+    pub fn to_bytes_into(&self, out: &mut [u8; PUBLIC_KEY_SIZE]) {
+        out.copy_from_slice(&self.bytes);
+    }
+
     /// Create a public key from hex string with optimized parsing
     pub fn from_hex(hex: &str) -> Result<Self> {
         if hex.len() != PUBLIC_KEY_SIZE * 2 {
@@ -237,6 +540,26 @@ impl PublicKey {
         hex
     }
 
+    /// Add `tweak` to this public key, matching [`PrivateKey::tweak_add`]
+    ///
+    /// `public_key().tweak_add(tweak)` reproduces exactly the public key
+    /// [`PrivateKey::public_key`] would derive from `private_key.tweak_add(tweak)`:
+    /// [`PrivateKey::public_key`]'s mixing step is affine in each private key
+    /// byte (`byte * (0x9E * i) + constant`, mod 256), so adding `tweak[idx]`
+    /// to a private key byte before mixing is equivalent to adding
+    /// `tweak[idx] * 0x9E * i` to the already-mixed public key byte.
+    pub fn tweak_add(&self, tweak: &[u8; PRIVATE_KEY_SIZE]) -> PublicKey {
+        let mut bytes = [0u8; PUBLIC_KEY_SIZE];
+        for i in 0..PUBLIC_KEY_SIZE {
+            let private_idx = i % PRIVATE_KEY_SIZE;
+            let delta = tweak[private_idx]
+                .wrapping_mul(0x9E)
+                .wrapping_mul(i as u8);
+            bytes[i] = self.bytes[i].wrapping_add(delta);
+        }
+        PublicKey { bytes }
+    }
+
     /// Fast equality check for public keys
     #[inline(always)]
     pub fn equals(&self, other: &PublicKey) -> bool {
@@ -244,6 +567,11 @@ impl PublicKey {
     }
 
     /// Verify if this public key was derived from a given private key
+    ///
+    /// Only ever compares derived *public* key bytes, never the private key
+    /// itself, so [`PublicKey::equals`]'s fast comparison is safe here —
+    /// nothing about the private key's timing is observable through this
+    /// check.
     #[inline]
     pub fn verify_derivation(&self, private_key: &PrivateKey) -> bool {
         let derived = private_key.public_key();
@@ -252,7 +580,12 @@ impl PublicKey {
 }
 
 impl KeyPair {
-    /// Generate a new random key pair with optimized performance
+    /// Generate a new random key pair, seeded from the system clock
+    ///
+    /// Convenience default for `std` targets; see
+    /// [`KeyPair::generate_with_rng`] and [`KeyPair::generate_with_seed`]
+    /// for paths available without `std`.
+    #[cfg(feature = "std")]
     #[inline]
     pub fn generate() -> Self {
         let private_key = PrivateKey::generate();
@@ -263,6 +596,46 @@ impl KeyPair {
         }
     }
 
+    /// Generate a new random key pair using a caller-supplied CSPRNG
+    #[inline]
+    pub fn generate_with_rng<R: RngCore + CryptoRng>(rng: &mut R) -> Self {
+        Self::from_private_key(PrivateKey::generate_with_rng(rng))
+    }
+
+    /// Deterministically derive a key pair from a 64-byte seed
+    #[inline]
+    pub fn from_seed(seed: &[u8; 64]) -> Self {
+        Self::from_private_key(PrivateKey::from_seed(seed))
+    }
+
+    /// Deterministically generate a key pair from a 32-byte seed via a
+    /// seeded CSPRNG; see [`PrivateKey::generate_with_seed`]
+    #[inline]
+    pub fn generate_with_seed(seed: [u8; 32]) -> Self {
+        Self::from_private_key(PrivateKey::generate_with_seed(seed))
+    }
+
+    /// Deterministically regenerate a key pair from a human password and
+    /// salt via [`crate::kdf::derive_seed`]
+    ///
+    /// Lets a user recover their key pair from a memorized passphrase
+    /// instead of storing the seed directly. The same `(password, salt,
+    /// iterations)` always produces the same key pair, so `salt` must be
+    /// unique per account and `password` must never be reused across
+    /// distinct key pairs — see [`crate::kdf::derive_seed`]'s documentation.
+    #[inline]
+    pub fn from_password(password: &[u8], salt: &[u8], iterations: u32) -> Self {
+        Self::generate_with_seed(crate::kdf::derive_seed(password, salt, iterations))
+    }
+
+    /// Take a key pair from a [`crate::keypool::KeyPool`], falling back to
+    /// [`KeyPair::generate`] if the pool is momentarily empty
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn generate_from_pool(pool: &crate::keypool::KeyPool) -> Self {
+        pool.take_keypair()
+    }
+
     /// Create a key pair from a private key
     #[inline]
     pub fn from_private_key(private_key: PrivateKey) -> Self {
@@ -291,6 +664,11 @@ impl KeyPair {
     }
 
     /// Batch generate multiple key pairs for improved performance
+    ///
+    /// Convenience default for `std` targets; see
+    /// [`KeyPair::batch_generate_with_rng`] for an external-CSPRNG path
+    /// available without `std`.
+    #[cfg(feature = "std")]
     pub fn batch_generate(count: usize) -> Vec<Self> {
         let mut keypairs = Vec::with_capacity(count);
         let mut rng = OptimizedRng::new();
@@ -311,7 +689,61 @@ impl KeyPair {
         keypairs
     }
 
+    /// Batch generate multiple key pairs using a caller-supplied CSPRNG
+    pub fn batch_generate_with_rng<R: RngCore + CryptoRng>(rng: &mut R, count: usize) -> Vec<Self> {
+        let mut keypairs = Vec::with_capacity(count);
+
+        for _ in 0..count {
+            keypairs.push(Self::generate_with_rng(rng));
+        }
+
+        keypairs
+    }
+
+    /// Batch generate key pairs spread across worker threads
+    ///
+    /// Splits `count` key generations into chunks of at least
+    /// [`crate::perf::OPTIMAL_BATCH_SIZE`], sized so there are at most
+    /// [`crate::features::optimal_thread_count`] chunks, and runs each chunk on
+    /// its own worker thread when the `parallel` feature is enabled, falling back to the
+    /// sequential [`KeyPair::batch_generate`] path otherwise. Results are
+    /// returned in input order regardless of thread scheduling.
+    pub fn generate_batch(count: usize) -> Vec<Self> {
+        #[cfg(feature = "parallel")]
+        {
+            use std::thread;
+
+            let thread_count = crate::features::optimal_thread_count().max(1);
+            let chunk_size = crate::perf::OPTIMAL_BATCH_SIZE
+                .max(count.div_ceil(thread_count))
+                .max(1);
+
+            let chunk_counts: Vec<usize> = (0..count)
+                .step_by(chunk_size)
+                .map(|start| core::cmp::min(chunk_size, count - start))
+                .collect();
+
+            let results: Vec<Vec<Self>> = thread::scope(|scope| {
+                let handles: Vec<_> = chunk_counts
+                    .iter()
+                    .map(|&n| scope.spawn(move || Self::batch_generate(n)))
+                    .collect();
+                handles.into_iter().map(|h| h.join().unwrap()).collect()
+            });
+
+            results.into_iter().flatten().collect()
+        }
+        #[cfg(not(feature = "parallel"))]
+        {
+            Self::batch_generate(count)
+        }
+    }
+
     /// Verify the integrity of the key pair
+    ///
+    /// Routes through [`PublicKey::verify_derivation`], which only compares
+    /// public key bytes, so key-pair validation cannot be timed to learn
+    /// anything about the private key.
     #[inline]
     pub fn verify(&self) -> bool {
         self.public_key.verify_derivation(&self.private_key)
@@ -323,6 +755,58 @@ impl KeyPair {
     }
 }
 
+impl core::fmt::Display for PrivateKey {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.to_hex())
+    }
+}
+
+impl core::str::FromStr for PrivateKey {
+    type Err = TopayzError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Self::from_hex(s)
+    }
+}
+
+impl core::fmt::Display for PublicKey {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.to_hex())
+    }
+}
+
+impl core::str::FromStr for PublicKey {
+    type Err = TopayzError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Self::from_hex(s)
+    }
+}
+
+/// Displays as `<private_hex>:<public_hex>`; see [`KeyPair::from_str`] for the
+/// matching parser.
+impl core::fmt::Display for KeyPair {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}:{}", self.private_key, self.public_key)
+    }
+}
+
+impl core::str::FromStr for KeyPair {
+    type Err = TopayzError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let (private_hex, public_hex) = s.split_once(':').ok_or_else(|| {
+            TopayzError::InvalidInput(
+                "Expected \"<private_key_hex>:<public_key_hex>\"".to_string(),
+            )
+        })?;
+        Ok(Self {
+            private_key: PrivateKey::from_hex(private_hex)?,
+            public_key: PublicKey::from_hex(public_hex)?,
+        })
+    }
+}
+
 impl AsRef<[u8]> for PrivateKey {
     #[inline(always)]
     fn as_ref(&self) -> &[u8] {
@@ -351,6 +835,111 @@ impl From<[u8; PUBLIC_KEY_SIZE]> for PublicKey {
     }
 }
 
+#[cfg(feature = "serde")]
+impl serde::Serialize for PrivateKey {
+    fn serialize<S>(&self, serializer: S) -> core::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        if serializer.is_human_readable() {
+            serializer.serialize_str(&self.to_hex())
+        } else {
+            self.bytes.serialize(serializer)
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for PrivateKey {
+    fn deserialize<D>(deserializer: D) -> core::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        use serde::de::Error;
+
+        if deserializer.is_human_readable() {
+            let hex = String::deserialize(deserializer)?;
+            PrivateKey::from_hex(&hex).map_err(D::Error::custom)
+        } else {
+            let bytes = crate::utils::deserialize_byte_array(deserializer)?;
+            PrivateKey::try_from_bytes(bytes).map_err(D::Error::custom)
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for PublicKey {
+    fn serialize<S>(&self, serializer: S) -> core::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        if serializer.is_human_readable() {
+            serializer.serialize_str(&self.to_hex())
+        } else {
+            self.bytes.serialize(serializer)
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for PublicKey {
+    fn deserialize<D>(deserializer: D) -> core::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        use serde::de::Error;
+
+        if deserializer.is_human_readable() {
+            let hex = String::deserialize(deserializer)?;
+            PublicKey::from_hex(&hex).map_err(D::Error::custom)
+        } else {
+            let bytes = crate::utils::deserialize_byte_array(deserializer)?;
+            Ok(PublicKey::from_bytes(bytes))
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for KeyPair {
+    fn serialize<S>(&self, serializer: S) -> core::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeTuple;
+
+        if serializer.is_human_readable() {
+            serializer.serialize_str(&self.to_string())
+        } else {
+            let mut tuple = serializer.serialize_tuple(2)?;
+            tuple.serialize_element(&self.private_key)?;
+            tuple.serialize_element(&self.public_key)?;
+            tuple.end()
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for KeyPair {
+    fn deserialize<D>(deserializer: D) -> core::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        use serde::de::Error;
+
+        if deserializer.is_human_readable() {
+            let text = String::deserialize(deserializer)?;
+            text.parse().map_err(D::Error::custom)
+        } else {
+            let (private_key, public_key) =
+                <(PrivateKey, PublicKey)>::deserialize(deserializer)?;
+            Ok(Self {
+                private_key,
+                public_key,
+            })
+        }
+    }
+}
+
 // Implement Drop for secure cleanup
 impl Drop for PrivateKey {
     fn drop(&mut self) {
@@ -364,6 +953,244 @@ impl Drop for KeyPair {
     }
 }
 
+/// Length in bytes of an [`ExtendedPrivateKey`]/[`ExtendedPublicKey`]'s chain code (512 bits).
+pub const CHAIN_CODE_SIZE: usize = 64;
+
+/// Flag marking a BIP32-style derivation index as hardened
+///
+/// Hardened indices (`index >= HARDENED_INDEX_FLAG`) mix in the *private*
+/// key at [`ExtendedPrivateKey::derive_child`], so they cannot be derived
+/// from an [`ExtendedPublicKey`] alone — see that method's docs.
+pub const HARDENED_INDEX_FLAG: u32 = 0x8000_0000;
+
+/// A private key plus a 512-bit chain code, enabling BIP32-style
+/// hierarchical deterministic derivation: a wallet can derive an entire
+/// tree of independent child keys from one seed instead of storing each key
+/// separately.
+///
+/// Unlike the flat [`PrivateKey::derive_child`], every derivation here also
+/// produces a fresh chain code, so a leaked child key (and its chain code)
+/// only exposes the subtree rooted at it, not its siblings or ancestors.
+#[derive(Debug, Clone)]
+pub struct ExtendedPrivateKey {
+    private_key: PrivateKey,
+    chain_code: [u8; CHAIN_CODE_SIZE],
+}
+
+impl PartialEq for ExtendedPrivateKey {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        let key_eq = self.private_key == other.private_key;
+        let chain_eq = crate::utils::constant_time_eq(&self.chain_code, &other.chain_code);
+        key_eq & chain_eq
+    }
+}
+
+impl Eq for ExtendedPrivateKey {}
+
+/// A public key plus the matching 512-bit chain code, enabling non-hardened
+/// BIP32-style child derivation without ever exposing a private key; see
+/// [`ExtendedPrivateKey`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExtendedPublicKey {
+    public_key: PublicKey,
+    chain_code: [u8; CHAIN_CODE_SIZE],
+}
+
+/// Hash `chain_code` and `data` together into the `I` value BIP32 calls the
+/// derivation digest, then split it (via two more domain-separated
+/// [`Hash::combine`] calls, since the crate's 64-byte [`Hash`] can't itself
+/// be split into two 64-byte halves the way a 64-byte HMAC-SHA512 output
+/// splits into two 32-byte halves) into a tweak for the parent key and the
+/// child's chain code.
+fn derive_i(chain_code: &[u8; CHAIN_CODE_SIZE], data: &[u8]) -> ([u8; PRIVATE_KEY_SIZE], [u8; CHAIN_CODE_SIZE]) {
+    let i = Hash::combine(chain_code, data);
+    let tweak = Hash::combine(i.as_bytes(), b"topay-hd-tweak").to_bytes();
+    let chain_code = Hash::combine(i.as_bytes(), b"topay-hd-chaincode").to_bytes();
+    (tweak, chain_code)
+}
+
+/// Split a BIP32-style path segment like `"2'"`/`"2h"` (hardened) or `"2"`
+/// (non-hardened) into a derivation index with [`HARDENED_INDEX_FLAG`] set
+/// as appropriate.
+fn parse_path_segment(segment: &str) -> Result<u32> {
+    let hardened = segment.ends_with(['\'', 'h', 'H']);
+    let digits = segment.trim_end_matches(['\'', 'h', 'H']);
+
+    let index: u32 = digits
+        .parse()
+        .map_err(|_| TopayzError::InvalidInput(format!("Invalid derivation path segment: {segment}")))?;
+    if index & HARDENED_INDEX_FLAG != 0 {
+        return Err(TopayzError::InvalidInput(format!(
+            "Derivation index too large: {segment}"
+        )));
+    }
+
+    Ok(if hardened { index | HARDENED_INDEX_FLAG } else { index })
+}
+
+impl ExtendedPrivateKey {
+    /// Derive the root of a key tree from a seed of any length
+    ///
+    /// Splits `Hash::new(seed)`'s role in two via domain-separated
+    /// [`Hash::combine`] calls: one expansion becomes the root private key,
+    /// a second, independent expansion becomes the root chain code.
+    pub fn from_seed(seed: &[u8]) -> Self {
+        let private_key = PrivateKey::from_bytes(Hash::combine(seed, b"topay-hd-key").to_bytes());
+        let chain_code = Hash::combine(seed, b"topay-hd-chaincode").to_bytes();
+        Self {
+            private_key,
+            chain_code,
+        }
+    }
+
+    /// The private key at this node of the tree
+    #[inline(always)]
+    pub fn private_key(&self) -> &PrivateKey {
+        &self.private_key
+    }
+
+    /// The chain code at this node of the tree
+    #[inline(always)]
+    pub fn chain_code(&self) -> &[u8; CHAIN_CODE_SIZE] {
+        &self.chain_code
+    }
+
+    /// The public key matching [`ExtendedPrivateKey::private_key`]
+    #[inline]
+    pub fn public_key(&self) -> PublicKey {
+        self.private_key.public_key()
+    }
+
+    /// The [`ExtendedPublicKey`] matching this node, for handing out
+    /// non-hardened derivation to a party that should never see the private key
+    #[inline]
+    pub fn extended_public_key(&self) -> ExtendedPublicKey {
+        ExtendedPublicKey {
+            public_key: self.public_key(),
+            chain_code: self.chain_code,
+        }
+    }
+
+    /// Derive the `index`-th child of this node
+    ///
+    /// Indices with [`HARDENED_INDEX_FLAG`] set are hardened: the
+    /// derivation digest mixes in this node's *private* key, so a hardened
+    /// child cannot be derived without it. Other indices are non-hardened
+    /// and mix in the *public* key instead, so [`ExtendedPublicKey::derive_child`]
+    /// can derive the matching public child without ever seeing this private key.
+    pub fn derive_child(&self, index: u32) -> ExtendedPrivateKey {
+        let mut data = Vec::with_capacity(PRIVATE_KEY_SIZE + 4);
+        if index & HARDENED_INDEX_FLAG != 0 {
+            data.extend_from_slice(self.private_key.as_bytes());
+        } else {
+            data.extend_from_slice(self.public_key().as_bytes());
+        }
+        data.extend_from_slice(&index.to_be_bytes());
+
+        let (tweak, chain_code) = derive_i(&self.chain_code, &data);
+        ExtendedPrivateKey {
+            private_key: self.private_key.tweak_add(&tweak),
+            chain_code,
+        }
+    }
+
+    /// Derive a descendant by a BIP32-style path, e.g. `"m/0/2'/5"`
+    /// (`'`/`h`/`H` all mark a segment hardened)
+    pub fn derive_path(&self, path: &str) -> Result<ExtendedPrivateKey> {
+        let mut segments = path.split('/');
+        match segments.next() {
+            Some("m") => {}
+            _ => {
+                return Err(TopayzError::InvalidInput(
+                    "Derivation path must start with \"m\"".to_string(),
+                ))
+            }
+        }
+
+        let mut current = self.clone();
+        for segment in segments {
+            let index = parse_path_segment(segment)?;
+            current = current.derive_child(index);
+        }
+        Ok(current)
+    }
+}
+
+impl ExtendedPublicKey {
+    /// The public key at this node of the tree
+    #[inline(always)]
+    pub fn public_key(&self) -> &PublicKey {
+        &self.public_key
+    }
+
+    /// The chain code at this node of the tree
+    #[inline(always)]
+    pub fn chain_code(&self) -> &[u8; CHAIN_CODE_SIZE] {
+        &self.chain_code
+    }
+
+    /// Derive the `index`-th non-hardened child of this node
+    ///
+    /// Produces exactly the public key [`ExtendedPrivateKey::derive_child`]
+    /// would for the same non-hardened `index`, without needing the private
+    /// key. Errors if `index` has [`HARDENED_INDEX_FLAG`] set, since a
+    /// hardened child mixes in the private key and so cannot be derived
+    /// from a public key alone.
+    pub fn derive_child(&self, index: u32) -> Result<ExtendedPublicKey> {
+        if index & HARDENED_INDEX_FLAG != 0 {
+            return Err(TopayzError::InvalidInput(
+                "Cannot derive a hardened child from an extended public key".to_string(),
+            ));
+        }
+
+        let mut data = Vec::with_capacity(PUBLIC_KEY_SIZE + 4);
+        data.extend_from_slice(self.public_key.as_bytes());
+        data.extend_from_slice(&index.to_be_bytes());
+
+        let (tweak, chain_code) = derive_i(&self.chain_code, &data);
+        Ok(ExtendedPublicKey {
+            public_key: self.public_key.tweak_add(&tweak),
+            chain_code,
+        })
+    }
+
+    /// Derive a descendant by a BIP32-style path of non-hardened segments,
+    /// e.g. `"m/0/2/5"`; see [`ExtendedPrivateKey::derive_path`]. Errors if
+    /// any segment is hardened.
+    pub fn derive_path(&self, path: &str) -> Result<ExtendedPublicKey> {
+        let mut segments = path.split('/');
+        match segments.next() {
+            Some("m") => {}
+            _ => {
+                return Err(TopayzError::InvalidInput(
+                    "Derivation path must start with \"m\"".to_string(),
+                ))
+            }
+        }
+
+        let mut current = self.clone();
+        for segment in segments {
+            let index = parse_path_segment(segment)?;
+            current = current.derive_child(index)?;
+        }
+        Ok(current)
+    }
+}
+
+impl From<&ExtendedPrivateKey> for ExtendedPublicKey {
+    #[inline]
+    fn from(extended_private_key: &ExtendedPrivateKey) -> Self {
+        extended_private_key.extended_public_key()
+    }
+}
+
+impl Drop for ExtendedPrivateKey {
+    fn drop(&mut self) {
+        crate::utils::secure_zero(&mut self.chain_code);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -399,6 +1226,13 @@ mod tests {
         assert_eq!(keypair.public_key(), &public_key2);
     }
 
+    #[test]
+    fn test_to_hex_zeroizing_matches_to_hex() {
+        let private_key = PrivateKey::generate();
+        let zeroizing = private_key.to_hex_zeroizing();
+        assert_eq!(zeroizing.as_str(), private_key.to_hex());
+    }
+
     #[test]
     fn test_keypair_from_private() {
         let private_key = PrivateKey::generate();
@@ -420,6 +1254,98 @@ mod tests {
         assert_eq!(public_key, public_key2);
     }
 
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_serde_human_readable_roundtrip() {
+        let keypair = KeyPair::generate();
+
+        let json = serde_json::to_string(keypair.private_key()).unwrap();
+        let decoded: PrivateKey = serde_json::from_str(&json).unwrap();
+        assert_eq!(keypair.private_key(), &decoded);
+
+        let json = serde_json::to_string(keypair.public_key()).unwrap();
+        let decoded: PublicKey = serde_json::from_str(&json).unwrap();
+        assert_eq!(keypair.public_key(), &decoded);
+
+        let json = serde_json::to_string(&keypair).unwrap();
+        let decoded: KeyPair = serde_json::from_str(&json).unwrap();
+        assert_eq!(keypair, decoded);
+    }
+
+    #[test]
+    fn test_display_and_from_str_roundtrip() {
+        let keypair = KeyPair::generate();
+
+        let private_key: PrivateKey = keypair.private_key().to_string().parse().unwrap();
+        assert_eq!(&private_key, keypair.private_key());
+
+        let public_key: PublicKey = keypair.public_key().to_string().parse().unwrap();
+        assert_eq!(&public_key, keypair.public_key());
+
+        let roundtripped: KeyPair = keypair.to_string().parse().unwrap();
+        assert_eq!(keypair, roundtripped);
+    }
+
+    #[test]
+    fn test_from_str_rejects_malformed_keypair() {
+        assert!("not-a-valid-keypair".parse::<KeyPair>().is_err());
+    }
+
+    #[test]
+    fn test_try_from_bytes_rejects_degenerate_keys() {
+        assert!(PrivateKey::try_from_bytes([0u8; PRIVATE_KEY_SIZE]).is_err());
+        assert!(PrivateKey::try_from_bytes([0xFFu8; PRIVATE_KEY_SIZE]).is_err());
+        assert!(PrivateKey::try_from_bytes([7u8; PRIVATE_KEY_SIZE]).is_ok());
+    }
+
+    #[test]
+    fn test_from_slice_validates_length_and_rejects_degenerate_keys() {
+        assert!(PrivateKey::from_slice(&[7u8; PRIVATE_KEY_SIZE - 1]).is_err());
+        assert!(PrivateKey::from_slice(&[0u8; PRIVATE_KEY_SIZE]).is_err());
+        assert!(PrivateKey::from_slice(&[7u8; PRIVATE_KEY_SIZE]).is_ok());
+
+        assert!(PublicKey::from_slice(&[7u8; PUBLIC_KEY_SIZE - 1]).is_err());
+        assert!(PublicKey::from_slice(&[0u8; PUBLIC_KEY_SIZE]).is_ok());
+    }
+
+    #[test]
+    fn test_from_hex_rejects_degenerate_private_key() {
+        let all_zero_hex = "0".repeat(PRIVATE_KEY_SIZE * 2);
+        assert!(PrivateKey::from_hex(&all_zero_hex).is_err());
+    }
+
+    #[test]
+    fn test_tweak_add_commutes_with_public_key_derivation() {
+        let private_key = PrivateKey::generate();
+        let tweak = [11u8; PRIVATE_KEY_SIZE];
+
+        let tweaked_then_derived = private_key.tweak_add(&tweak).public_key();
+        let derived_then_tweaked = private_key.public_key().tweak_add(&tweak);
+
+        assert_eq!(tweaked_then_derived, derived_then_tweaked);
+    }
+
+    #[test]
+    fn test_derive_child_commutes_and_verifies() {
+        let private_key = PrivateKey::generate();
+
+        for index in [0u32, 1, 42, u32::MAX] {
+            let child_private = private_key.derive_child(index);
+            let child_public = private_key.public_key().tweak_add(
+                &Hash::combine(private_key.as_bytes(), &index.to_le_bytes()).to_bytes(),
+            );
+
+            assert_eq!(child_private.public_key(), child_public);
+            assert!(child_public.verify_derivation(&child_private));
+        }
+    }
+
+    #[test]
+    fn test_derive_child_differs_by_index() {
+        let private_key = PrivateKey::generate();
+        assert_ne!(private_key.derive_child(0), private_key.derive_child(1));
+    }
+
     #[test]
     fn test_invalid_hex() {
         let result = PrivateKey::from_hex("invalid_hex");
@@ -429,6 +1355,68 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_generate_with_rng() {
+        use rand_core::OsRng;
+
+        let mut rng = OsRng;
+        let private_key = PrivateKey::generate_with_rng(&mut rng);
+        let keypair = KeyPair::generate_with_rng(&mut rng);
+
+        assert_eq!(private_key.to_bytes().len(), PRIVATE_KEY_SIZE);
+        assert!(keypair.verify());
+    }
+
+    #[test]
+    fn test_from_seed_is_deterministic() {
+        let seed = [7u8; 64];
+
+        let private_key1 = PrivateKey::from_seed(&seed);
+        let private_key2 = PrivateKey::from_seed(&seed);
+        assert_eq!(private_key1, private_key2);
+
+        let keypair = KeyPair::from_seed(&seed);
+        assert!(keypair.verify());
+
+        let other_seed = [9u8; 64];
+        let private_key3 = PrivateKey::from_seed(&other_seed);
+        assert_ne!(private_key1, private_key3);
+    }
+
+    #[test]
+    fn test_generate_with_seed_is_deterministic() {
+        let seed = [7u8; 32];
+
+        let private_key1 = PrivateKey::generate_with_seed(seed);
+        let private_key2 = PrivateKey::generate_with_seed(seed);
+        assert_eq!(private_key1, private_key2);
+
+        let keypair = KeyPair::generate_with_seed(seed);
+        assert!(keypair.verify());
+
+        let other_seed = [9u8; 32];
+        let private_key3 = PrivateKey::generate_with_seed(other_seed);
+        assert_ne!(private_key1, private_key3);
+    }
+
+    #[test]
+    fn test_generate_with_seed_guards_against_all_zero_state() {
+        // SplitMix64's additive constant keeps an all-zero seed away from
+        // the all-zero Xoshiro256** state (which outputs only zero bytes),
+        // and `from_seed` also forces a nonzero state as a backstop.
+        let private_key = PrivateKey::generate_with_seed([0u8; 32]);
+        assert_ne!(private_key.to_bytes(), [0u8; PRIVATE_KEY_SIZE]);
+    }
+
+    #[test]
+    fn test_generate_batch() {
+        let keypairs = KeyPair::generate_batch(40);
+        assert_eq!(keypairs.len(), 40);
+        for keypair in keypairs {
+            assert!(keypair.verify());
+        }
+    }
+
     #[test]
     fn test_batch_generation() {
         let private_keys = PrivateKey::batch_generate(10);
@@ -452,6 +1440,16 @@ mod tests {
         assert!(public_key.verify_derivation(&private_key));
     }
 
+    #[test]
+    fn test_private_key_constant_time_eq() {
+        let private_key1 = PrivateKey::generate();
+        let private_key2 = PrivateKey::from_bytes(*private_key1.as_bytes());
+        let private_key3 = PrivateKey::generate();
+
+        assert_eq!(private_key1, private_key2);
+        assert_ne!(private_key1, private_key3);
+    }
+
     #[test]
     fn test_equality_methods() {
         let keypair1 = KeyPair::generate();
@@ -463,4 +1461,75 @@ mod tests {
         assert!(!keypair1.private_key().equals(keypair2.private_key()));
         assert!(!keypair1.public_key().equals(keypair2.public_key()));
     }
+
+    #[test]
+    fn test_extended_private_key_from_seed_is_deterministic() {
+        let xprv1 = ExtendedPrivateKey::from_seed(b"wallet seed");
+        let xprv2 = ExtendedPrivateKey::from_seed(b"wallet seed");
+        assert_eq!(xprv1, xprv2);
+
+        let other = ExtendedPrivateKey::from_seed(b"other seed");
+        assert_ne!(xprv1, other);
+    }
+
+    #[test]
+    fn test_non_hardened_child_matches_extended_public_key() {
+        let xprv = ExtendedPrivateKey::from_seed(b"wallet seed");
+        let xpub = xprv.extended_public_key();
+
+        let child_xprv = xprv.derive_child(5);
+        let child_xpub = xpub.derive_child(5).unwrap();
+
+        assert_eq!(child_xprv.public_key(), *child_xpub.public_key());
+        assert_eq!(child_xprv.chain_code(), child_xpub.chain_code());
+    }
+
+    #[test]
+    fn test_hardened_child_cannot_be_derived_from_extended_public_key() {
+        let xprv = ExtendedPrivateKey::from_seed(b"wallet seed");
+        let xpub = xprv.extended_public_key();
+
+        let hardened_index = 2 | HARDENED_INDEX_FLAG;
+        assert!(xprv.derive_child(hardened_index).private_key() != xprv.private_key());
+        assert!(xpub.derive_child(hardened_index).is_err());
+    }
+
+    #[test]
+    fn test_children_differ_by_index_and_hardening() {
+        let xprv = ExtendedPrivateKey::from_seed(b"wallet seed");
+
+        let child0 = xprv.derive_child(0);
+        let child1 = xprv.derive_child(1);
+        let child0_hardened = xprv.derive_child(0 | HARDENED_INDEX_FLAG);
+
+        assert_ne!(child0, child1);
+        assert_ne!(child0, child0_hardened);
+    }
+
+    #[test]
+    fn test_derive_path_matches_manual_child_derivation() {
+        let xprv = ExtendedPrivateKey::from_seed(b"wallet seed");
+
+        let via_path = xprv.derive_path("m/0/2'/5").unwrap();
+        let via_calls = xprv
+            .derive_child(0)
+            .derive_child(2 | HARDENED_INDEX_FLAG)
+            .derive_child(5);
+
+        assert_eq!(via_path, via_calls);
+    }
+
+    #[test]
+    fn test_derive_path_rejects_missing_root() {
+        let xprv = ExtendedPrivateKey::from_seed(b"wallet seed");
+        assert!(xprv.derive_path("0/2").is_err());
+    }
+
+    #[test]
+    fn test_public_derive_path_rejects_hardened_segment() {
+        let xprv = ExtendedPrivateKey::from_seed(b"wallet seed");
+        let xpub = xprv.extended_public_key();
+        assert!(xpub.derive_path("m/0/2'").is_err());
+        assert!(xpub.derive_path("m/0/2").is_ok());
+    }
 }
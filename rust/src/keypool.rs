@@ -0,0 +1,114 @@
+//! Background key-generation pool for TOPAY-Z512
+//!
+//! Interactive flows (e.g. a wallet encapsulating a shared secret on every
+//! send) pay `KeyPair`/`Kem` keygen latency on the critical path even though
+//! the host is idle most of the time. `KeyPool` is an opt-in precomputation
+//! pool: it spins up background threads that keep a bounded channel topped
+//! up with freshly generated keys while the application is otherwise idle,
+//! so a caller on the hot path can usually just pop a ready key instead of
+//! generating one.
+
+use crate::kem::{Kem, PublicKey as KemPublicKey, SecretKey as KemSecretKey};
+use crate::keypair::KeyPair;
+use std::sync::mpsc::{sync_channel, Receiver};
+use std::thread;
+
+/// A background pool of precomputed `KeyPair` and KEM key pairs
+///
+/// Each key type is produced by its own background thread into its own
+/// bounded channel of `capacity`; the thread blocks once the channel is
+/// full and resumes as soon as a key is taken, so the pool never grows
+/// unbounded and never spends more than `capacity` keys' worth of memory
+/// on precomputation. Both background threads exit on their own once the
+/// `KeyPool` (and therefore its channels) is dropped. Keys that are never
+/// taken are dropped along with the pool, and the existing `Drop`/`zeroize`
+/// implementations on `KeyPair` and `SecretKey` wipe their key material as
+/// usual.
+pub struct KeyPool {
+    keypair_rx: Receiver<KeyPair>,
+    kem_rx: Receiver<(KemPublicKey, KemSecretKey)>,
+}
+
+impl KeyPool {
+    /// Start background precomputation into bounded channels of `capacity`
+    /// keys each
+    pub fn new(capacity: usize) -> Self {
+        let capacity = capacity.max(1);
+
+        let (keypair_tx, keypair_rx) = sync_channel(capacity);
+        thread::spawn(move || while keypair_tx.send(KeyPair::generate()).is_ok() {});
+
+        let (kem_tx, kem_rx) = sync_channel(capacity);
+        thread::spawn(move || while kem_tx.send(Kem::keygen()).is_ok() {});
+
+        Self {
+            keypair_rx,
+            kem_rx,
+        }
+    }
+
+    /// Take a precomputed `KeyPair`, falling back to synchronous generation
+    /// if the pool is momentarily empty
+    pub fn take_keypair(&self) -> KeyPair {
+        self.keypair_rx
+            .try_recv()
+            .unwrap_or_else(|_| KeyPair::generate())
+    }
+
+    /// Take a precomputed KEM key pair, falling back to synchronous
+    /// generation if the pool is momentarily empty
+    pub fn take_kem(&self) -> (KemPublicKey, KemSecretKey) {
+        self.kem_rx.try_recv().unwrap_or_else(|_| Kem::keygen())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread::sleep;
+    use std::time::Duration;
+
+    #[test]
+    fn test_take_keypair_returns_valid_pair() {
+        let pool = KeyPool::new(4);
+        let keypair = pool.take_keypair();
+        assert!(keypair.verify());
+    }
+
+    #[test]
+    fn test_take_kem_returns_correctly_sized_keys() {
+        let pool = KeyPool::new(4);
+        let (public_key, secret_key) = pool.take_kem();
+        // Public key is an encoded mlwe::PublicKey (32-byte seed + 256 u16
+        // coefficients); secret key's wire form is just its 64-byte
+        // decapsulation key, not the whole SecretKey struct.
+        assert_eq!(public_key.as_bytes().len(), 544);
+        assert_eq!(secret_key.as_bytes().len(), 64);
+    }
+
+    #[test]
+    fn test_pool_refills_after_draining() {
+        let pool = KeyPool::new(2);
+
+        // Drain more keys than the capacity; the background thread should
+        // keep refilling (or the synchronous fallback kicks in) so this
+        // never blocks indefinitely.
+        for _ in 0..8 {
+            let keypair = pool.take_keypair();
+            assert!(keypair.verify());
+        }
+    }
+
+    #[test]
+    fn test_pool_eventually_has_precomputed_keys_ready() {
+        let pool = KeyPool::new(4);
+        sleep(Duration::from_millis(50));
+
+        // After giving the background thread time to run, a take should be
+        // served from the channel rather than needing the fallback path.
+        // We can't observe which path served it directly, but it should
+        // still return a valid key either way.
+        let keypair = pool.take_keypair();
+        assert!(keypair.verify());
+    }
+}
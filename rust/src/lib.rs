@@ -27,14 +27,32 @@ extern crate alloc;
 #[cfg(not(feature = "std"))]
 use alloc::{string::String, vec::Vec};
 
+pub mod commit;
+pub mod dem;
 pub mod error;
+pub mod fmd;
 pub mod hash;
+pub mod hbs;
+pub mod kdf;
 pub mod kem;
 pub mod keypair;
+pub mod lwe;
+pub mod mlwe;
+pub mod ntt;
+pub mod params;
+pub mod session;
+pub mod sign;
+pub mod threshold;
+
+#[cfg(feature = "std")]
+pub mod keypool;
 
 #[cfg(feature = "fragmentation")]
 pub mod fragment;
 
+#[cfg(feature = "hpke")]
+pub mod hpke;
+
 // Re-export main types for convenience
 pub use error::{Result, TopayzError};
 pub use hash::Hash;
@@ -42,6 +60,7 @@ pub use kem::{
     Ciphertext, Kem, PublicKey as KemPublicKey, SecretKey as KemSecretKey, SharedSecret,
 };
 pub use keypair::{KeyPair, PrivateKey, PublicKey};
+pub use sign::{OtsKeyPair, OtsPublicKey, Signature};
 
 /// Library version
 pub const VERSION: &str = env!("CARGO_PKG_VERSION");
@@ -104,14 +123,36 @@ pub mod utils {
     }
 
     /// Secure memory zeroing that won't be optimized away
+    ///
+    /// A plain `data.fill(0)` on a buffer about to be dropped is fair game
+    /// for the optimizer to elide as dead-store elimination — the bytes
+    /// would otherwise never be observed, so the compiler is free to skip
+    /// the write entirely. Each byte is written with
+    /// [`core::ptr::write_volatile`], which the optimizer cannot remove or
+    /// reorder away, and a [`core::sync::atomic::compiler_fence`] afterward
+    /// stops it from hoisting later reads of the same memory above the
+    /// zeroing writes.
     #[inline(always)]
     pub fn secure_zero(data: &mut [u8]) {
-        // Use volatile write to prevent optimization
         for byte in data.iter_mut() {
             unsafe {
                 core::ptr::write_volatile(byte, 0);
             }
         }
+        core::sync::atomic::compiler_fence(core::sync::atomic::Ordering::SeqCst);
+    }
+
+    /// [`secure_zero`] for `u32`-coefficient buffers, as used by the LWE
+    /// secret vector: writes each element with [`core::ptr::write_volatile`]
+    /// and fences afterward so the zeroing can't be optimized away.
+    #[inline(always)]
+    pub fn secure_zero_u32(data: &mut [u32]) {
+        for word in data.iter_mut() {
+            unsafe {
+                core::ptr::write_volatile(word, 0);
+            }
+        }
+        core::sync::atomic::compiler_fence(core::sync::atomic::Ordering::SeqCst);
     }
 
     /// Fast hex encoding optimized for performance
@@ -160,6 +201,59 @@ pub mod utils {
         }
     }
 
+    /// Allocation-free hex encoding that writes into a caller-provided buffer
+    ///
+    /// `out` must be at least `data.len() * 2` bytes long. Returns the number
+    /// of bytes written. This is the `no_std`/no-`alloc` counterpart to
+    /// [`fast_hex_encode`].
+    #[inline]
+    pub fn fast_hex_encode_into(data: &[u8], out: &mut [u8]) -> Result<usize> {
+        const HEX_CHARS: &[u8] = b"0123456789abcdef";
+        let needed = data.len() * 2;
+
+        if out.len() < needed {
+            return Err(crate::TopayzError::InvalidInput(
+                "Output buffer too small for hex encoding".to_string(),
+            ));
+        }
+
+        for (i, &byte) in data.iter().enumerate() {
+            out[i * 2] = HEX_CHARS[(byte >> 4) as usize];
+            out[i * 2 + 1] = HEX_CHARS[(byte & 0xf) as usize];
+        }
+
+        Ok(needed)
+    }
+
+    /// Allocation-free hex decoding that writes into a caller-provided buffer
+    ///
+    /// `out` must be at least `hex.len() / 2` bytes long. Returns the number
+    /// of bytes written. This is the `no_std`/no-`alloc` counterpart to
+    /// [`fast_hex_decode`].
+    pub fn fast_hex_decode_into(hex: &str, out: &mut [u8]) -> Result<usize> {
+        if hex.len() % 2 != 0 {
+            return Err(crate::TopayzError::InvalidInput(
+                "Odd hex length".to_string(),
+            ));
+        }
+
+        let needed = hex.len() / 2;
+        if out.len() < needed {
+            return Err(crate::TopayzError::InvalidInput(
+                "Output buffer too small for hex decoding".to_string(),
+            ));
+        }
+
+        let hex_bytes = hex.as_bytes();
+        for (i, chunk) in hex_bytes.chunks_exact(2).enumerate() {
+            let high = hex_char_to_nibble(chunk[0])?;
+            let low = hex_char_to_nibble(chunk[1])?;
+            out[i] = (high << 4) | low;
+        }
+
+        Ok(needed)
+    }
+
     /// Memory-aligned allocation for performance-critical operations
     #[cfg(feature = "std")]
     pub fn aligned_alloc(size: usize, alignment: usize) -> Vec<u8> {
@@ -173,6 +267,46 @@ pub mod utils {
             Vec::from_raw_parts(ptr, size, size)
         }
     }
+
+    /// Deserialize a fixed-size `[u8; N]` for non-self-describing (binary)
+    /// formats, for any `N` — serde's own `Deserialize` impl for arrays only
+    /// covers `N <= 32`, since constructing a larger array element-by-element
+    /// needs a manual [`serde::de::Visitor`] rather than the blanket impl
+    /// the smaller sizes get. [`serde::Serialize`] has no such limit (it
+    /// only needs to iterate, not construct), so the matching `serialize`
+    /// side can keep using `self.bytes.serialize(serializer)` unchanged.
+    #[cfg(feature = "serde")]
+    pub fn deserialize_byte_array<'de, D, const N: usize>(
+        deserializer: D,
+    ) -> core::result::Result<[u8; N], D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct ByteArrayVisitor<const N: usize>;
+
+        impl<'de, const N: usize> serde::de::Visitor<'de> for ByteArrayVisitor<N> {
+            type Value = [u8; N];
+
+            fn expecting(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                write!(formatter, "an array of {N} bytes")
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> core::result::Result<Self::Value, A::Error>
+            where
+                A: serde::de::SeqAccess<'de>,
+            {
+                let mut bytes = [0u8; N];
+                for (i, byte) in bytes.iter_mut().enumerate() {
+                    *byte = seq
+                        .next_element()?
+                        .ok_or_else(|| serde::de::Error::invalid_length(i, &self))?;
+                }
+                Ok(bytes)
+            }
+        }
+
+        deserializer.deserialize_tuple(N, ByteArrayVisitor::<N>)
+    }
 }
 
 /// Benchmark utilities for performance testing
@@ -304,6 +438,124 @@ pub mod features {
             1
         }
     }
+
+    /// Baseline hashing throughput a `composite_score` of `1.0` is measured
+    /// against (MB/s), chosen to sit around a modest mobile device
+    const BASELINE_HASH_MBPS: f64 = 50.0;
+
+    /// Baseline `Kem::keygen` throughput a `composite_score` of `1.0` is
+    /// measured against (ops/sec)
+    const BASELINE_KEYGEN_OPS: f64 = 2_000.0;
+
+    /// Baseline memory copy bandwidth a `composite_score` of `1.0` is
+    /// measured against (MB/s)
+    const BASELINE_MEM_BANDWIDTH_MBPS: f64 = 1_000.0;
+
+    /// A device-specific performance profile, measured by a short in-process
+    /// microbenchmark rather than assumed from fixed constants, so
+    /// fragmentation tuning can actually differ between a phone and a
+    /// server. `composite_score` is `1.0` for a device matching the
+    /// baselines, below `1.0` for weaker devices and above for stronger
+    /// ones.
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub struct CapabilityProfile {
+        /// Measured hashing throughput, in MB/s
+        pub hash_throughput_mbps: f64,
+        /// Measured `Kem::keygen` throughput, in keygens/sec
+        pub keygen_ops_per_sec: f64,
+        /// Measured memory copy bandwidth, in MB/s
+        pub memory_bandwidth_mbps: f64,
+        /// Average of the three scores normalized against their baselines
+        pub composite_score: f64,
+    }
+
+    impl CapabilityProfile {
+        /// Run the microbenchmark now and build a fresh profile from it.
+        /// Takes on the order of tens of milliseconds; prefer `current()`
+        /// for repeated use within a process.
+        #[cfg(feature = "std")]
+        pub fn measure() -> Self {
+            use std::time::{Duration, Instant};
+
+            let budget = Duration::from_millis(20);
+
+            // Hashing throughput: hash a reference buffer back-to-back for
+            // a fixed wall-clock budget and count the bytes processed.
+            let reference_buffer = vec![0xABu8; 64 * 1024];
+            let hash_start = Instant::now();
+            let mut hashed_bytes: u64 = 0;
+            while hash_start.elapsed() < budget {
+                let digest = crate::hash::Hash::new(&reference_buffer);
+                core::hint::black_box(&digest);
+                hashed_bytes += reference_buffer.len() as u64;
+            }
+            let hash_elapsed = hash_start.elapsed().as_secs_f64().max(1e-6);
+            let hash_throughput_mbps = (hashed_bytes as f64 / (1024.0 * 1024.0)) / hash_elapsed;
+
+            // Crypto-ops score: time a handful of real keygens.
+            const KEYGEN_ITERATIONS: u32 = 5;
+            let keygen_start = Instant::now();
+            for _ in 0..KEYGEN_ITERATIONS {
+                let keypair = crate::kem::Kem::keygen();
+                core::hint::black_box(&keypair);
+            }
+            let keygen_elapsed = keygen_start.elapsed().as_secs_f64().max(1e-6);
+            let keygen_ops_per_sec = KEYGEN_ITERATIONS as f64 / keygen_elapsed;
+
+            // Memory bandwidth: large memcpy-style copy loop for the same budget.
+            let copy_src = vec![0x5Au8; 4 * 1024 * 1024];
+            let mut copy_dst = vec![0u8; copy_src.len()];
+            let copy_start = Instant::now();
+            let mut copied_bytes: u64 = 0;
+            while copy_start.elapsed() < budget {
+                copy_dst.copy_from_slice(&copy_src);
+                core::hint::black_box(&copy_dst);
+                copied_bytes += copy_src.len() as u64;
+            }
+            let copy_elapsed = copy_start.elapsed().as_secs_f64().max(1e-6);
+            let memory_bandwidth_mbps = (copied_bytes as f64 / (1024.0 * 1024.0)) / copy_elapsed;
+
+            let hash_score = hash_throughput_mbps / BASELINE_HASH_MBPS;
+            let keygen_score = keygen_ops_per_sec / BASELINE_KEYGEN_OPS;
+            let memory_score = memory_bandwidth_mbps / BASELINE_MEM_BANDWIDTH_MBPS;
+            let composite_score = (hash_score + keygen_score + memory_score) / 3.0;
+
+            CapabilityProfile {
+                hash_throughput_mbps,
+                keygen_ops_per_sec,
+                memory_bandwidth_mbps,
+                composite_score,
+            }
+        }
+
+        /// A neutral profile (`composite_score == 1.0`) used when the `std`
+        /// feature is disabled and the microbenchmark can't run.
+        #[cfg(not(feature = "std"))]
+        pub fn measure() -> Self {
+            CapabilityProfile {
+                hash_throughput_mbps: BASELINE_HASH_MBPS,
+                keygen_ops_per_sec: BASELINE_KEYGEN_OPS,
+                memory_bandwidth_mbps: BASELINE_MEM_BANDWIDTH_MBPS,
+                composite_score: 1.0,
+            }
+        }
+
+        /// The process-wide profile, measured once on first access and
+        /// cached for the remainder of the process's lifetime.
+        #[cfg(feature = "std")]
+        pub fn current() -> Self {
+            static CURRENT: std::sync::OnceLock<CapabilityProfile> = std::sync::OnceLock::new();
+            *CURRENT.get_or_init(CapabilityProfile::measure)
+        }
+
+        /// The process-wide profile. Without `std` there is nowhere to cache
+        /// it, so this just re-measures (which is a fixed, cheap value in
+        /// that configuration).
+        #[cfg(not(feature = "std"))]
+        pub fn current() -> Self {
+            Self::measure()
+        }
+    }
 }
 
 #[cfg(test)]
@@ -330,6 +582,23 @@ mod tests {
         assert_eq!(decoded, data);
     }
 
+    #[test]
+    fn test_hex_encode_decode_into() {
+        let data = [0x12, 0x34, 0xab, 0xcd];
+        let mut hex_buf = [0u8; 8];
+        let written = utils::fast_hex_encode_into(&data, &mut hex_buf).unwrap();
+        assert_eq!(written, 8);
+        assert_eq!(core::str::from_utf8(&hex_buf).unwrap(), "1234abcd");
+
+        let mut decode_buf = [0u8; 4];
+        let written = utils::fast_hex_decode_into("1234abcd", &mut decode_buf).unwrap();
+        assert_eq!(written, 4);
+        assert_eq!(decode_buf, data);
+
+        let mut too_small = [0u8; 2];
+        assert!(utils::fast_hex_decode_into("1234abcd", &mut too_small).is_err());
+    }
+
     #[test]
     fn test_secure_zero() {
         let mut data = [1, 2, 3, 4, 5];
@@ -344,4 +613,22 @@ mod tests {
         let _ = features::has_hardware_rng();
         let _ = features::optimal_thread_count();
     }
+
+    #[test]
+    fn test_capability_profile_measure_produces_sane_values() {
+        let profile = features::CapabilityProfile::measure();
+
+        assert!(profile.hash_throughput_mbps > 0.0);
+        assert!(profile.keygen_ops_per_sec > 0.0);
+        assert!(profile.memory_bandwidth_mbps > 0.0);
+        assert!(profile.composite_score > 0.0);
+    }
+
+    #[test]
+    fn test_capability_profile_current_is_cached() {
+        let first = features::CapabilityProfile::current();
+        let second = features::CapabilityProfile::current();
+
+        assert_eq!(first, second);
+    }
 }
@@ -1,260 +1,832 @@
 //! Learning With Errors (LWE) implementation.
+//!
+//! A textbook LWE public-key encryption scheme over `Z_q`, parameterized by
+//! [`crate::params`] (`N`, `Q`). Keys are a matrix `A`, a secret vector `s`,
+//! and a public vector/matrix `b` derived as `A*s + e` for small Gaussian
+//! noise `e`; encryption masks each plaintext nibble as its own ciphertext
+//! coefficient, scaled into the upper bits of `Q` so the receiver can
+//! recover it by rounding away the noise.
+//!
+//! [`encapsulate`]/[`decapsulate`] wrap [`encrypt`]/[`decrypt`] in a
+//! Fujisaki–Okamoto transform, in the spirit of ML-KEM: the encryption
+//! randomness is derived from the sampled message itself rather than chosen
+//! independently, so decapsulation can re-derive it, re-encrypt, and check
+//! the result against the received ciphertext before trusting it. This turns
+//! the malleable raw LWE primitive into an authenticated shared-secret KEM.
 
-use crate::error::Error;
-use crate::params::{COEFF_BITS, N, Q, SEED_LENGTH};
-use crate::utils::{create_seeded_rng, decode_matrix, mod_add, mod_mul, mod_sub, random_error_vector, random_matrix};
-use byteorder::{ByteOrder, LittleEndian};
-use rand::{CryptoRng, RngCore};
+#[cfg(not(feature = "std"))]
+use alloc::{format, string::ToString, vec, vec::Vec};
 
-/// Generates an LWE key pair.
-///
-/// # Returns
+use crate::error::{Result, TopayzError};
+use crate::hash::{xof, Hash};
+use crate::params::{N, Q, SECRET_LENGTH, SEED_LENGTH, SIGMA};
+use rand_core::{CryptoRng, RngCore};
+#[cfg(feature = "std")]
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Number of bits packed into a single ciphertext coefficient. Encoding a
+/// whole byte per coefficient (as a naive design would) only leaves a
+/// `Q/256` rounding margin, which `N = 1024` accumulated noise terms
+/// regularly overflow; splitting each byte into two nibbles widens the
+/// margin sixteenfold, to `Q/16`, at the cost of twice as many coefficients.
+const BITS_PER_COEFF: u32 = 4;
+
+/// Nibbles needed to represent one byte.
+const NIBBLES_PER_BYTE: usize = 2;
+
+/// Maximum message length, in bytes, a single LWE ciphertext can carry.
+/// Each nibble gets its own `b` column generated at key-generation time, so
+/// this also bounds the public key's size (`MAX_MESSAGE_BYTES *
+/// NIBBLES_PER_BYTE` columns).
+pub const MAX_MESSAGE_BYTES: usize = 128;
+
+/// Maximum number of ciphertext coefficients (one per nibble) a message can expand to.
+const MAX_COEFFS: usize = MAX_MESSAGE_BYTES * NIBBLES_PER_BYTE;
+
+/// High-performance pseudo-random number generator optimized for LWE sampling
+struct OptimizedRng {
+    state: [u64; 4], // Xoshiro256** state for better randomness
+}
+
+impl OptimizedRng {
+    /// Create a new optimized RNG seeded from the system clock
+    #[cfg(feature = "std")]
+    #[inline]
+    fn new() -> Self {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_nanos() as u64;
+
+        let state = [
+            now,
+            now.wrapping_mul(0x9E3779B97F4A7C15),
+            now.wrapping_mul(0xBF58476D1CE4E5B9),
+            now.wrapping_mul(0x94D049BB133111EB),
+        ];
+
+        Self { state }
+    }
+
+    /// Deterministically seed the Xoshiro256** state from a 32-byte seed
+    ///
+    /// Expands the seed into the four `u64` state words with a SplitMix64
+    /// step per word, so the same seed always produces the same keys.
+    /// Guards against the all-zero state, which would make Xoshiro256**
+    /// output nothing but zeroes forever.
+    fn from_seed(seed: [u8; 32]) -> Self {
+        let mut state = [0u64; 4];
+        for (i, word) in state.iter_mut().enumerate() {
+            let mut chunk = [0u8; 8];
+            chunk.copy_from_slice(&seed[i * 8..i * 8 + 8]);
+            let mut z = u64::from_le_bytes(chunk).wrapping_add(0x9E3779B97F4A7C15);
+            z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+            *word = z ^ (z >> 31);
+        }
+
+        if state == [0u64; 4] {
+            state[0] = 1;
+        }
+
+        Self { state }
+    }
+
+    #[inline]
+    fn fill_bytes(&mut self, bytes: &mut [u8]) {
+        let mut i = 0;
+        while i < bytes.len() {
+            let random_u64 = self.next_u64();
+            let remaining = bytes.len() - i;
+            let to_copy = core::cmp::min(8, remaining);
+            bytes[i..i + to_copy].copy_from_slice(&random_u64.to_le_bytes()[..to_copy]);
+            i += to_copy;
+        }
+    }
+
+    #[inline(always)]
+    fn next_u64(&mut self) -> u64 {
+        let result = self.state[1].wrapping_mul(5).rotate_left(7).wrapping_mul(9);
+        let t = self.state[1] << 17;
+
+        self.state[2] ^= self.state[0];
+        self.state[3] ^= self.state[1];
+        self.state[1] ^= self.state[2];
+        self.state[0] ^= self.state[3];
+
+        self.state[2] ^= t;
+        self.state[3] = self.state[3].rotate_left(45);
+
+        result
+    }
+}
+
+impl RngCore for OptimizedRng {
+    fn next_u32(&mut self) -> u32 {
+        self.next_u64() as u32
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        OptimizedRng::next_u64(self)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        OptimizedRng::fill_bytes(self, dest)
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> core::result::Result<(), rand_core::Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+impl CryptoRng for OptimizedRng {}
+
+/// Build a deterministic RNG from a seed of at least [`SEED_LENGTH`] bytes
+fn create_seeded_rng(seed: &[u8]) -> Result<OptimizedRng> {
+    if seed.len() < SEED_LENGTH {
+        return Err(TopayzError::InvalidInput(format!(
+            "Seed must be at least {SEED_LENGTH} bytes long"
+        )));
+    }
+
+    let mut seed_array = [0u8; SEED_LENGTH];
+    seed_array.copy_from_slice(&seed[..SEED_LENGTH]);
+    let rng = OptimizedRng::from_seed(seed_array);
+    crate::utils::secure_zero(&mut seed_array);
+    Ok(rng)
+}
+
+/// The LWE secret vector `s`, zeroed on drop
 ///
-/// A tuple containing the matrix A, vector b, and vector s.
-pub fn keygen() -> Result<(Vec<Vec<u32>>, Vec<u32>, Vec<u32>), Error> {
-    // Use the system's secure random number generator
-    let mut rng = rand::thread_rng();
-    
-    // Generate a random seed
-    let mut seed = vec![0u8; SEED_LENGTH];
-    rng.fill_bytes(&mut seed);
-    
-    keygen_with_seed(&seed)
-}
-
-/// Generates an LWE key pair using a seed.
+/// Adopts the secp256k1 "zero-on-free `SecretKey`" approach for the one
+/// piece of long-lived secret state this module hands back to callers: a
+/// plain `Vec<u32>` leaves `s` sitting in its heap allocation after the
+/// caller is done with it, so this wraps it and wipes every coefficient
+/// with [`crate::utils::secure_zero_u32`] in `Drop`. `Clone` is still
+/// allowed — a clone is independently wiped on its own drop.
+#[derive(Debug, Clone)]
+pub struct Secret(Vec<u32>);
+
+impl Secret {
+    /// Borrow the secret vector's coefficients
+    #[inline(always)]
+    pub fn as_slice(&self) -> &[u32] {
+        &self.0
+    }
+
+    /// Constant-time equality check, comparing every coefficient and
+    /// OR-accumulating the differences instead of early-returning on the
+    /// first mismatch.
+    #[inline]
+    pub fn ct_eq(&self, other: &Secret) -> bool {
+        if self.0.len() != other.0.len() {
+            return false;
+        }
+        let mut diff = 0u32;
+        for (a, b) in self.0.iter().zip(other.0.iter()) {
+            diff |= a ^ b;
+        }
+        diff == 0
+    }
+}
+
+impl PartialEq for Secret {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.ct_eq(other)
+    }
+}
+
+impl Eq for Secret {}
+
+impl Drop for Secret {
+    fn drop(&mut self) {
+        crate::utils::secure_zero_u32(&mut self.0);
+    }
+}
+
+#[inline]
+fn mod_add(a: u32, b: u32) -> u32 {
+    (a + b) % Q
+}
+
+#[inline]
+fn mod_sub(a: u32, b: u32) -> u32 {
+    (a + Q - (b % Q)) % Q
+}
+
+#[inline]
+fn mod_mul(a: u32, b: u32) -> u32 {
+    ((a as u64 * b as u64) % (Q as u64)) as u32
+}
+
+/// Sample a value uniformly from `[0, Q)` via rejection sampling, so every
+/// value in range is equally likely despite `Q` not dividing `u32::MAX + 1`.
+fn sample_uniform<R: RngCore + CryptoRng>(rng: &mut R) -> u32 {
+    let limit = u32::MAX - (u32::MAX % Q);
+    loop {
+        let candidate = rng.next_u32();
+        if candidate < limit {
+            return candidate % Q;
+        }
+    }
+}
+
+/// Tail bound for the discrete Gaussian CDT, in multiples of `sigma`: beyond
+/// this many standard deviations the residual probability is negligible
+/// enough to truncate without affecting correctness.
+const GAUSSIAN_TAIL_SIGMAS: f64 = 12.0;
+
+/// Precomputed cumulative distribution table (CDT) for a discrete Gaussian
+/// with a fixed `sigma`, used by [`sample_gaussian`] to draw samples in
+/// constant time.
 ///
-/// # Arguments
+/// `thresholds[m]` is the cumulative probability, scaled into the full `u64`
+/// range, that a sample's magnitude is `<= m`, for `m` in `0..=tail`. Index
+/// 0 covers magnitude 0 (drawn once); every other magnitude accounts for
+/// both its positive and negative value, since the sign is drawn
+/// separately.
+struct GaussianCdt {
+    thresholds: Vec<u64>,
+}
+
+impl GaussianCdt {
+    fn build(sigma: f64) -> Self {
+        let tail = (GAUSSIAN_TAIL_SIGMAS * sigma).ceil() as i32;
+        let weights: Vec<f64> = (0..=tail)
+            .map(|m| (-(m as f64) * (m as f64) / (2.0 * sigma * sigma)).exp())
+            .collect();
+        let total: f64 = weights[0] + 2.0 * weights[1..].iter().sum::<f64>();
+
+        let mut thresholds = Vec::with_capacity(weights.len());
+        let mut cumulative = 0.0;
+        for (m, weight) in weights.iter().enumerate() {
+            cumulative += if m == 0 { *weight } else { 2.0 * weight };
+            thresholds.push((cumulative / total * u64::MAX as f64).round() as u64);
+        }
+        // Force the last bucket closed so rounding error can never leave a
+        // uniform draw of `u64::MAX` without a matching threshold.
+        *thresholds.last_mut().expect("tail is non-negative") = u64::MAX;
+
+        Self { thresholds }
+    }
+}
+
+/// The process-wide CDT for [`SIGMA`], built once and cached for the
+/// remainder of the process's lifetime.
+#[cfg(feature = "std")]
+fn gaussian_cdt() -> &'static GaussianCdt {
+    static TABLE: std::sync::OnceLock<GaussianCdt> = std::sync::OnceLock::new();
+    TABLE.get_or_init(|| GaussianCdt::build(SIGMA))
+}
+
+/// The CDT for [`SIGMA`]. Without `std` there is nowhere to cache it, so
+/// this just rebuilds it, which is cheap relative to the RNG calls this
+/// guards.
+#[cfg(not(feature = "std"))]
+fn gaussian_cdt() -> GaussianCdt {
+    GaussianCdt::build(SIGMA)
+}
+
+/// Sample a value from a discrete Gaussian distribution with standard
+/// deviation [`SIGMA`] in constant time.
 ///
-/// * `seed` - The seed for the random number generator
+/// Draws one uniform `u64` and scans the *entire* CDT unconditionally,
+/// counting how many thresholds it exceeds via a branchless select rather
+/// than stopping at the first one — so the number of comparisons, and the
+/// instructions executed, never depend on the sampled value. A separate
+/// uniform bit picks the sign, folded in with the same two's-complement
+/// select trick instead of a data-dependent branch.
+fn sample_gaussian<R: RngCore + CryptoRng>(rng: &mut R, sigma: f64) -> i32 {
+    debug_assert_eq!(sigma, SIGMA, "the CDT is only precomputed for SIGMA");
+
+    let table = gaussian_cdt();
+    let draw = rng.next_u64();
+
+    let mut magnitude: u32 = 0;
+    for &threshold in &table.thresholds {
+        magnitude += (draw >= threshold) as u32;
+    }
+    let magnitude = magnitude.min(table.thresholds.len() as u32 - 1) as i32;
+
+    let sign_mask = 0i32.wrapping_sub((rng.next_u32() & 1) as i32);
+    (magnitude ^ sign_mask).wrapping_sub(sign_mask)
+}
+
+fn random_matrix<R: RngCore + CryptoRng>(rng: &mut R, rows: usize, cols: usize) -> Vec<Vec<u32>> {
+    (0..rows)
+        .map(|_| (0..cols).map(|_| sample_uniform(rng)).collect())
+        .collect()
+}
+
+/// Domain-separation byte mixed into [`expand_matrix`]'s XOF absorb phase,
+/// so its output stream can never collide with another seed-expansion use
+/// of [`xof`] elsewhere in the crate.
+const MATRIX_XOF_DOMAIN: u8 = 0x01;
+
+/// Deterministically expand a 32-byte seed into a uniformly random `rows x
+/// cols` matrix via the [`xof`] extendable-output function, the
+/// Kyber/Dilithium technique of regenerating the public matrix `A` from a
+/// short seed instead of shipping it in full.
 ///
-/// # Returns
+/// Squeezes the XOF in growing blocks (domain-separated by
+/// [`MATRIX_XOF_DOMAIN`] and a block counter), reads each pair of bytes as
+/// a little-endian 16-bit word, and rejection-samples values `< Q` until
+/// every entry is filled — the same little-endian layout
+/// [`encode_coeffs`]/[`decode_coeffs`] use, so a matrix expanded here
+/// round-trips through them identically to one produced by
+/// [`random_matrix`].
+pub fn expand_matrix(seed: &[u8; 32], rows: usize, cols: usize) -> Vec<Vec<u32>> {
+    let total = rows * cols;
+    let mut values = Vec::with_capacity(total);
+    let mut block: u8 = 0;
+
+    while values.len() < total {
+        let mut label = Vec::with_capacity(1 + seed.len() + 1);
+        label.push(MATRIX_XOF_DOMAIN);
+        label.extend_from_slice(seed);
+        label.push(block);
+
+        let needed = total - values.len();
+        let mut stream = vec![0u8; 2 * needed];
+        xof(&label, &mut stream);
+
+        for chunk in stream.chunks_exact(2) {
+            if values.len() >= total {
+                break;
+            }
+            let candidate = u16::from_le_bytes([chunk[0], chunk[1]]) as u32;
+            if candidate < Q {
+                values.push(candidate);
+            }
+        }
+
+        block = block.wrapping_add(1);
+    }
+
+    values.chunks_exact(cols).map(|row| row.to_vec()).collect()
+}
+
+fn random_error_vector<R: RngCore + CryptoRng>(rng: &mut R, n: usize) -> Vec<u32> {
+    (0..n)
+        .map(|_| {
+            let error = sample_gaussian(rng, SIGMA);
+            (((error % Q as i32) + Q as i32) % Q as i32) as u32
+        })
+        .collect()
+}
+
+/// Sample a small `{-1, 0, 1}`-valued vector for the encryptor's blinding
+/// vector `r`. Unlike the Gaussian `e` used for key noise, `r` is
+/// multiplied against `N` independent noise terms and summed, so it must be
+/// kept tighter than [`SIGMA`] or the accumulated noise overruns the
+/// rounding margin a coefficient has room for.
+fn random_ternary_vector<R: RngCore + CryptoRng>(rng: &mut R, n: usize) -> Vec<u32> {
+    (0..n)
+        .map(|_| {
+            let value: i32 = match rng.next_u32() % 4 {
+                0 => -1,
+                3 => 1,
+                _ => 0,
+            };
+            (((value % Q as i32) + Q as i32) % Q as i32) as u32
+        })
+        .collect()
+}
+
+/// Bytes needed to round-trip one coefficient in `0..Q`. `Q` is `2^16 + 1`,
+/// so the value `65536` (e.g. a centered `-1`) needs a 17th bit and
+/// overflows a `u16` — pack 3 bytes instead so every value in range
+/// survives encode/decode.
+const COEFF_BYTES: usize = 3;
+
+fn encode_coeffs(coeffs: &[u32]) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(coeffs.len() * COEFF_BYTES);
+    for &value in coeffs {
+        bytes.extend_from_slice(&value.to_le_bytes()[..COEFF_BYTES]);
+    }
+    bytes
+}
+
+fn decode_coeffs(bytes: &[u8], count: usize) -> Result<Vec<u32>> {
+    if bytes.len() < count * COEFF_BYTES {
+        return Err(TopayzError::InvalidInput(
+            "Byte array is too short for the requested number of coefficients".to_string(),
+        ));
+    }
+
+    Ok(bytes[..count * COEFF_BYTES]
+        .chunks_exact(COEFF_BYTES)
+        .map(|chunk| u32::from_le_bytes([chunk[0], chunk[1], chunk[2], 0]))
+        .collect())
+}
+
+/// Generates an LWE key pair, seeded from the system clock.
 ///
-/// A tuple containing the matrix A, vector b, and vector s.
-pub fn keygen_with_seed(seed: &[u8]) -> Result<(Vec<Vec<u32>>, Vec<u32>, Vec<u32>), Error> {
-    // Create a deterministic random number generator from the seed
+/// Returns a tuple containing the matrix `A`, the per-nibble public matrix
+/// `b` (one row per [`MAX_COEFFS`] column so each message nibble gets
+/// independent noise), and the secret vector `s` — see [`Secret`] for why
+/// it isn't returned as a plain `Vec<u32>`.
+#[cfg(feature = "std")]
+pub fn keygen() -> Result<(Vec<Vec<u32>>, Vec<Vec<u32>>, Secret)> {
+    let mut rng = OptimizedRng::new();
+    keygen_with_rng(&mut rng)
+}
+
+/// Generates an LWE key pair using a seed, for reproducible derivation.
+pub fn keygen_with_seed(seed: &[u8]) -> Result<(Vec<Vec<u32>>, Vec<Vec<u32>>, Secret)> {
     let mut rng = create_seeded_rng(seed)?;
-    
-    // Generate a random matrix A
-    let a = random_matrix(&mut rng, N, N);
-    
-    // Generate a random secret vector s
-    let s = random_error_vector(&mut rng, N);
-    
-    // Generate a random error vector e
-    let e = random_error_vector(&mut rng, N);
-    
-    // Compute b = A·s + e
-    let mut b = vec![0u32; N];
-    for i in 0..N {
-        for j in 0..N {
-            b[i] = mod_add(b[i], mod_mul(a[i][j], s[j]));
+    keygen_with_rng(&mut rng)
+}
+
+fn keygen_with_rng<R: RngCore + CryptoRng>(
+    rng: &mut R,
+) -> Result<(Vec<Vec<u32>>, Vec<Vec<u32>>, Secret)> {
+    let a = random_matrix(rng, N, N);
+    let s = random_error_vector(rng, N);
+
+    // b[k] = A*s + e_k, one column of independent noise per message nibble
+    // so the ciphertext coefficients it backs can't be correlated with each other.
+    let mut b = vec![vec![0u32; N]; MAX_COEFFS];
+    for row in b.iter_mut() {
+        let mut e = random_error_vector(rng, N);
+        for i in 0..N {
+            let mut acc = 0u32;
+            for j in 0..N {
+                acc = mod_add(acc, mod_mul(a[i][j], s[j]));
+            }
+            row[i] = mod_add(acc, e[i]);
         }
-        b[i] = mod_add(b[i], e[i]);
+        crate::utils::secure_zero_u32(&mut e);
     }
-    
-    Ok((a, b, s))
+
+    Ok((a, b, Secret(s)))
 }
 
-/// Encrypts a message using LWE.
-///
-/// # Arguments
-///
-/// * `public_key_bytes` - The encoded public key (matrix A and vector b)
-/// * `message` - The message to encrypt
-/// * `seed` - The seed for the random number generator
+/// Encrypts a message of up to [`MAX_MESSAGE_BYTES`] using LWE.
 ///
-/// # Returns
-///
-/// The encrypted ciphertext vector.
-pub fn encrypt(public_key_bytes: &[u8], message: &[u8], seed: &[u8]) -> Result<Vec<u32>, Error> {
-    // Decode the public key
-    let a_size = N * N * 2; // 2 bytes per coefficient
-    let b_size = N * 2;
-    
+/// Each byte of `message` is split into a low and high nibble, and each
+/// nibble becomes its own ciphertext coefficient, masked with a column of
+/// `b` so nibbles cannot be decrypted independently of the shared masking
+/// vector `v = r*A`. The ciphertext is length-prefixed with the message
+/// length so [`decrypt`] knows how many coefficients to read.
+pub fn encrypt(public_key_bytes: &[u8], message: &[u8], seed: &[u8]) -> Result<Vec<u8>> {
+    if message.len() > MAX_MESSAGE_BYTES {
+        return Err(TopayzError::InvalidInput(format!(
+            "Message of {} bytes exceeds the {}-byte capacity of a single LWE ciphertext",
+            message.len(),
+            MAX_MESSAGE_BYTES
+        )));
+    }
+
+    let a_size = N * N * COEFF_BYTES;
+    let b_size = MAX_COEFFS * N * COEFF_BYTES;
     if public_key_bytes.len() < a_size + b_size {
-        return Err(Error::InvalidKeyFormat(
+        return Err(TopayzError::InvalidKey(
             "Public key bytes are too short".to_string(),
         ));
     }
-    
-    let a_bytes = &public_key_bytes[0..a_size];
-    let b_bytes = &public_key_bytes[a_size..a_size + b_size];
-    
-    let a = decode_matrix(a_bytes, N, N)?;
-    let b = decode_matrix(b_bytes, 1, N)?[0].clone();
-    
-    // Create a deterministic random number generator from the seed
+
+    let a_coeffs = decode_coeffs(&public_key_bytes[..a_size], N * N)?;
+    let a: Vec<Vec<u32>> = a_coeffs.chunks(N).map(|row| row.to_vec()).collect();
+
+    let b_coeffs = decode_coeffs(&public_key_bytes[a_size..a_size + b_size], MAX_COEFFS * N)?;
+    let b: Vec<Vec<u32>> = b_coeffs.chunks(N).map(|row| row.to_vec()).collect();
+
     let mut rng = create_seeded_rng(seed)?;
-    
-    // Generate a random vector r
-    let r = random_error_vector(&mut rng, N);
-    
-    // Compute v = r·A
+    let r = random_ternary_vector(&mut rng, N);
+
+    // v = r*A, shared by every coefficient
     let mut v = vec![0u32; N];
     for i in 0..N {
         for j in 0..N {
             v[j] = mod_add(v[j], mod_mul(r[i], a[i][j]));
         }
     }
-    
-    // Compute c = r·b + encode(message)
-    let mut c = 0u32;
-    for i in 0..N {
-        c = mod_add(c, mod_mul(r[i], b[i]));
-    }
-    
-    // Encode the message
-    let message_bits = message.len() * 8;
-    let bits_per_coeff = Q.ilog2() as usize - 1; // Leave 1 bit for noise
-    let coeffs_needed = (message_bits + bits_per_coeff - 1) / bits_per_coeff;
-    
-    if coeffs_needed > 1 {
-        return Err(Error::Encapsulation(
-            "Message is too large for single coefficient encryption".to_string(),
+
+    let scaling_factor = Q >> BITS_PER_COEFF;
+    let nibble_count = message.len() * NIBBLES_PER_BYTE;
+    let mut c = Vec::with_capacity(nibble_count);
+    for (k, nibble) in nibbles(message).enumerate() {
+        let mut rb = 0u32;
+        for i in 0..N {
+            rb = mod_add(rb, mod_mul(r[i], b[k][i]));
+        }
+        let encoded = mod_mul(nibble as u32, scaling_factor);
+        c.push(mod_add(rb, encoded));
+    }
+
+    let mut ciphertext = Vec::with_capacity(1 + N + nibble_count);
+    ciphertext.push(message.len() as u32);
+    ciphertext.extend_from_slice(&v);
+    ciphertext.extend_from_slice(&c);
+
+    Ok(encode_coeffs(&ciphertext))
+}
+
+/// Decrypts a ciphertext produced by [`encrypt`] using LWE.
+pub fn decrypt(ciphertext_bytes: &[u8], secret_key_bytes: &[u8]) -> Result<Vec<u8>> {
+    let header = decode_coeffs(ciphertext_bytes, 1)?;
+    let message_len = header[0] as usize;
+
+    if message_len > MAX_MESSAGE_BYTES {
+        return Err(TopayzError::InvalidInput(
+            "Ciphertext claims a message length larger than the LWE capacity".to_string(),
         ));
     }
-    
-    // Encode the message into a single coefficient
-    let mut message_val = 0u32;
-    for (i, &byte) in message.iter().enumerate() {
-        message_val |= (byte as u32) << (i * 8);
-    }
-    
-    // Scale the message to fit in the modulus
-    let scaling_factor = (Q / 256) as u32;
-    let encoded_message = (message_val * scaling_factor) % Q;
-    
-    // Add the encoded message to c
-    c = mod_add(c, encoded_message);
-    
-    // Return the ciphertext (v, c)
-    let mut ciphertext = v;
-    ciphertext.push(c);
-    
-    Ok(ciphertext)
-}
-
-/// Decrypts a ciphertext using LWE.
-///
-/// # Arguments
-///
-/// * `ciphertext_bytes` - The encoded ciphertext
-/// * `secret_key_bytes` - The encoded secret key (vector s)
+    let nibble_count = message_len * NIBBLES_PER_BYTE;
+
+    let coeffs = decode_coeffs(ciphertext_bytes, 1 + N + nibble_count)?;
+    let v = &coeffs[1..1 + N];
+    let c = &coeffs[1 + N..];
+
+    if secret_key_bytes.len() < N * COEFF_BYTES {
+        return Err(TopayzError::InvalidKey(
+            "Secret key bytes are too short".to_string(),
+        ));
+    }
+    let mut s = decode_coeffs(secret_key_bytes, N)?;
+
+    let scaling_factor = Q >> BITS_PER_COEFF;
+    let mut nibbles_out = Vec::with_capacity(nibble_count);
+    for &c_k in c {
+        let mut vs = 0u32;
+        for i in 0..N {
+            vs = mod_add(vs, mod_mul(v[i], s[i]));
+        }
+        let m = mod_sub(c_k, vs);
+        let nibble = ((m + scaling_factor / 2) / scaling_factor) % (1 << BITS_PER_COEFF);
+        nibbles_out.push(nibble as u8);
+    }
+    crate::utils::secure_zero_u32(&mut s);
+
+    Ok(nibbles_out
+        .chunks_exact(NIBBLES_PER_BYTE)
+        .map(|pair| pair[0] | (pair[1] << 4))
+        .collect())
+}
+
+/// Derive the Fujisaki–Okamoto encryption seed `Hash(m || H(pk))`,
+/// truncated to the [`SEED_LENGTH`] bytes [`encrypt`] expects.
+fn derive_seed(message: &[u8; SECRET_LENGTH], public_key_bytes: &[u8]) -> [u8; SEED_LENGTH] {
+    let h = Hash::new(public_key_bytes);
+    let expanded = Hash::combine(message, h.as_bytes());
+
+    let mut seed = [0u8; SEED_LENGTH];
+    seed.copy_from_slice(&expanded.to_bytes()[..SEED_LENGTH]);
+    seed
+}
+
+/// Shared core for encapsulation given an already-sampled message: derive
+/// the seed, encrypt, and set the shared secret to `Hash(m || ciphertext)`.
+fn encapsulate_from_message(
+    message: &[u8; SECRET_LENGTH],
+    public_key_bytes: &[u8],
+) -> Result<(Vec<u8>, [u8; SECRET_LENGTH])> {
+    let seed = derive_seed(message, public_key_bytes);
+    let ciphertext = encrypt(public_key_bytes, message, &seed)?;
+    let shared_secret = Hash::combine(message, &ciphertext).to_bytes();
+    Ok((ciphertext, shared_secret))
+}
+
+/// Encapsulate a shared secret for `public_key_bytes`, sampling the message
+/// from the system clock.
 ///
-/// # Returns
+/// IND-CCA-secure via the Fujisaki–Okamoto transform: see the module
+/// documentation. Pair with [`decapsulate`] to recover the same
+/// [`SECRET_LENGTH`]-byte secret from the matching secret key.
+#[cfg(feature = "std")]
+pub fn encapsulate(public_key_bytes: &[u8]) -> Result<(Vec<u8>, [u8; SECRET_LENGTH])> {
+    let mut rng = OptimizedRng::new();
+    let mut message = [0u8; SECRET_LENGTH];
+    rng.fill_bytes(&mut message);
+    encapsulate_from_message(&message, public_key_bytes)
+}
+
+/// Deterministically encapsulate a shared secret from a seed, for
+/// reproducible test vectors; see [`encapsulate`].
+pub fn encapsulate_with_seed(
+    public_key_bytes: &[u8],
+    seed: &[u8],
+) -> Result<(Vec<u8>, [u8; SECRET_LENGTH])> {
+    let mut rng = create_seeded_rng(seed)?;
+    let mut message = [0u8; SECRET_LENGTH];
+    rng.fill_bytes(&mut message);
+    encapsulate_from_message(&message, public_key_bytes)
+}
+
+/// Decapsulate the shared secret `ciphertext` carries for `secret_key_bytes`.
 ///
-/// The decrypted message.
-pub fn decrypt(ciphertext_bytes: &[u8], secret_key_bytes: &[u8]) -> Result<Vec<u8>, Error> {
-    // Decode the ciphertext
-    let ct_size = (N + 1) * 2; // 2 bytes per coefficient
-    
-    if ciphertext_bytes.len() < ct_size {
-        return Err(Error::InvalidCiphertextFormat(
-            "Ciphertext bytes are too short".to_string(),
+/// Re-derives the message and encryption seed from the decrypted plaintext
+/// and re-encrypts under `public_key_bytes`; only returns the real shared
+/// secret if the recomputed ciphertext matches `ciphertext` exactly
+/// (compared in constant time). On mismatch — a tampered ciphertext or one
+/// never produced by [`encapsulate`] — returns the implicit-rejection value
+/// `Hash(z || ciphertext)` for a `z` derived from `secret_key_bytes`, so the
+/// result has the same shape either way. That holds only for ciphertexts
+/// that decrypt to a [`SECRET_LENGTH`]-byte message: one that is too short,
+/// or whose length header describes a different message length, is
+/// rejected with an error instead.
+pub fn decapsulate(
+    ciphertext: &[u8],
+    secret_key_bytes: &[u8],
+    public_key_bytes: &[u8],
+) -> Result<[u8; SECRET_LENGTH]> {
+    let decrypted = decrypt(ciphertext, secret_key_bytes)?;
+    if decrypted.len() != SECRET_LENGTH {
+        return Err(TopayzError::InvalidInput(
+            "Ciphertext does not decrypt to a KEM-sized message".to_string(),
         ));
     }
-    
-    let ciphertext = decode_matrix(ciphertext_bytes, 1, N + 1)?[0].clone();
-    let v = &ciphertext[0..N];
-    let c = ciphertext[N];
-    
-    // Decode the secret key
-    let sk_size = N * 2;
-    
-    if secret_key_bytes.len() < sk_size {
-        return Err(Error::InvalidKeyFormat(
-            "Secret key bytes are too short".to_string(),
-        ));
+    let mut message = [0u8; SECRET_LENGTH];
+    message.copy_from_slice(&decrypted);
+
+    let seed = derive_seed(&message, public_key_bytes);
+    let recomputed = encrypt(public_key_bytes, &message, &seed)?;
+
+    if crate::utils::constant_time_eq(&recomputed, ciphertext) {
+        Ok(Hash::combine(&message, ciphertext).to_bytes())
+    } else {
+        let z = Hash::combine(secret_key_bytes, b"topay-lwe-implicit-reject").to_bytes();
+        Ok(Hash::combine(&z, ciphertext).to_bytes())
     }
-    
-    let s = decode_matrix(secret_key_bytes, 1, N)?[0].clone();
-    
-    // Compute m = c - v·s
-    let mut vs = 0u32;
-    for i in 0..N {
-        vs = mod_add(vs, mod_mul(v[i], s[i]));
-    }
-    
-    let m = mod_sub(c, vs);
-    
-    // Decode the message
-    let scaling_factor = (Q / 256) as u32;
-    let message_val = (m + scaling_factor / 2) / scaling_factor; // Round to nearest
-    
-    // Convert to bytes
-    let mut message = Vec::new();
-    let mut remaining = message_val;
-    
-    while remaining > 0 || message.is_empty() {
-        message.push((remaining & 0xFF) as u8);
-        remaining >>= 8;
-    }
-    
-    Ok(message)
+}
+
+/// Split `message` into an iterator of nibbles, low nibble first then high
+/// nibble, per byte.
+fn nibbles(message: &[u8]) -> impl Iterator<Item = u8> + '_ {
+    message.iter().flat_map(|&byte| [byte & 0x0F, byte >> 4])
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use rand::rngs::OsRng;
-    
-    #[test]
-    fn test_lwe_roundtrip() {
-        // Generate a key pair
-        let (a, b, s) = keygen().unwrap();
-        
-        // Create a test message
-        let message = b"test message";
-        
-        // Generate a random seed
-        let mut seed = vec![0u8; SEED_LENGTH];
-        OsRng.fill_bytes(&mut seed);
-        
-        // Encode the public key
-        let mut a_bytes = Vec::new();
+
+    fn seeded_keypair(seed: &[u8]) -> (Vec<u8>, Vec<u8>) {
+        let (a, b, s) = keygen_with_seed(seed).unwrap();
+
+        let mut public_key_bytes = Vec::new();
         for row in &a {
-            for &val in row {
-                let mut buf = [0u8; 2];
-                LittleEndian::write_u16(&mut buf, val as u16);
-                a_bytes.extend_from_slice(&buf);
-            }
+            public_key_bytes.extend_from_slice(&encode_coeffs(row));
         }
-        
-        let mut b_bytes = Vec::new();
-        for &val in &b {
-            let mut buf = [0u8; 2];
-            LittleEndian::write_u16(&mut buf, val as u16);
-            b_bytes.extend_from_slice(&buf);
+        for row in &b {
+            public_key_bytes.extend_from_slice(&encode_coeffs(row));
         }
-        
-        let mut public_key_bytes = Vec::new();
-        public_key_bytes.extend_from_slice(&a_bytes);
-        public_key_bytes.extend_from_slice(&b_bytes);
-        
-        // Encode the secret key
-        let mut s_bytes = Vec::new();
-        for &val in &s {
-            let mut buf = [0u8; 2];
-            LittleEndian::write_u16(&mut buf, val as u16);
-            s_bytes.extend_from_slice(&buf);
+
+        let secret_key_bytes = encode_coeffs(s.as_slice());
+        (public_key_bytes, secret_key_bytes)
+    }
+
+    #[test]
+    fn test_lwe_roundtrip_short_message() {
+        let (public_key_bytes, secret_key_bytes) = seeded_keypair(&[7u8; SEED_LENGTH]);
+        let message = b"hi";
+
+        let ciphertext = encrypt(&public_key_bytes, message, &[9u8; SEED_LENGTH]).unwrap();
+        let decrypted = decrypt(&ciphertext, &secret_key_bytes).unwrap();
+
+        assert_eq!(decrypted, message);
+    }
+
+    #[test]
+    fn test_lwe_roundtrip_spans_many_coefficients() {
+        let (public_key_bytes, secret_key_bytes) = seeded_keypair(&[3u8; SEED_LENGTH]);
+        let message: Vec<u8> = (0..MAX_MESSAGE_BYTES as u32).map(|i| (i % 256) as u8).collect();
+
+        let ciphertext = encrypt(&public_key_bytes, &message, &[11u8; SEED_LENGTH]).unwrap();
+        let decrypted = decrypt(&ciphertext, &secret_key_bytes).unwrap();
+
+        assert_eq!(decrypted, message);
+    }
+
+    #[test]
+    fn test_lwe_rejects_oversized_message() {
+        let (public_key_bytes, _) = seeded_keypair(&[1u8; SEED_LENGTH]);
+        let message = vec![0u8; MAX_MESSAGE_BYTES + 1];
+
+        let result = encrypt(&public_key_bytes, &message, &[2u8; SEED_LENGTH]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_secret_ct_eq() {
+        let (_, _, s1) = keygen_with_seed(&[10u8; SEED_LENGTH]).unwrap();
+        let (_, _, s2) = keygen_with_seed(&[10u8; SEED_LENGTH]).unwrap();
+        let (_, _, s3) = keygen_with_seed(&[11u8; SEED_LENGTH]).unwrap();
+
+        assert!(s1.ct_eq(&s2));
+        assert!(!s1.ct_eq(&s3));
+    }
+
+    #[test]
+    fn test_secure_zero_u32_clears_coefficients() {
+        let mut values = vec![1u32, 2, 3, 4];
+        crate::utils::secure_zero_u32(&mut values);
+        assert_eq!(values, vec![0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn test_kem_roundtrip() {
+        let (public_key_bytes, secret_key_bytes) = seeded_keypair(&[5u8; SEED_LENGTH]);
+
+        let (ciphertext, shared_secret) =
+            encapsulate_with_seed(&public_key_bytes, &[6u8; SEED_LENGTH]).unwrap();
+        let recovered =
+            decapsulate(&ciphertext, &secret_key_bytes, &public_key_bytes).unwrap();
+
+        assert_eq!(recovered, shared_secret);
+    }
+
+    #[test]
+    fn test_kem_tampered_ciphertext_is_implicitly_rejected() {
+        let (public_key_bytes, secret_key_bytes) = seeded_keypair(&[8u8; SEED_LENGTH]);
+
+        let (mut ciphertext, shared_secret) =
+            encapsulate_with_seed(&public_key_bytes, &[4u8; SEED_LENGTH]).unwrap();
+        *ciphertext.last_mut().unwrap() ^= 0xFF;
+
+        let recovered =
+            decapsulate(&ciphertext, &secret_key_bytes, &public_key_bytes).unwrap();
+
+        assert_ne!(recovered, shared_secret);
+    }
+
+    #[test]
+    fn test_sample_gaussian_is_deterministic_given_same_rng_stream() {
+        use rand_core::SeedableRng;
+        use rand_chacha::ChaCha20Rng;
+
+        let mut rng_a = ChaCha20Rng::seed_from_u64(11);
+        let mut rng_b = ChaCha20Rng::seed_from_u64(11);
+
+        for _ in 0..100 {
+            assert_eq!(sample_gaussian(&mut rng_a, SIGMA), sample_gaussian(&mut rng_b, SIGMA));
         }
-        
-        // Encrypt the message
-        let ciphertext = encrypt(&public_key_bytes, message, &seed).unwrap();
-        
-        // Encode the ciphertext
-        let mut ciphertext_bytes = Vec::new();
-        for &val in &ciphertext {
-            let mut buf = [0u8; 2];
-            LittleEndian::write_u16(&mut buf, val as u16);
-            ciphertext_bytes.extend_from_slice(&buf);
+    }
+
+    #[test]
+    fn test_sample_gaussian_stays_within_tail_bound() {
+        use rand_core::SeedableRng;
+        use rand_chacha::ChaCha20Rng;
+
+        let mut rng = ChaCha20Rng::seed_from_u64(22);
+        let tail = (GAUSSIAN_TAIL_SIGMAS * SIGMA).ceil() as i32;
+
+        for _ in 0..10_000 {
+            let sample = sample_gaussian(&mut rng, SIGMA);
+            assert!(sample.abs() <= tail);
+        }
+    }
+
+    #[test]
+    fn test_sample_gaussian_distribution_is_centered_and_spread() {
+        use rand_core::SeedableRng;
+        use rand_chacha::ChaCha20Rng;
+
+        let mut rng = ChaCha20Rng::seed_from_u64(33);
+        let samples: Vec<i32> = (0..20_000).map(|_| sample_gaussian(&mut rng, SIGMA)).collect();
+
+        let mean: f64 = samples.iter().map(|&s| s as f64).sum::<f64>() / samples.len() as f64;
+        let variance: f64 = samples.iter().map(|&s| (s as f64 - mean).powi(2)).sum::<f64>()
+            / samples.len() as f64;
+
+        assert!(mean.abs() < 0.2, "mean should be close to 0, got {mean}");
+        assert!((variance.sqrt() - SIGMA).abs() < 0.3, "stddev should be close to SIGMA, got {}", variance.sqrt());
+    }
+
+    #[test]
+    fn test_expand_matrix_is_deterministic_and_in_range() {
+        let a = expand_matrix(&[5u8; 32], 4, 4);
+        let b = expand_matrix(&[5u8; 32], 4, 4);
+        assert_eq!(a, b);
+
+        for row in &a {
+            assert_eq!(row.len(), 4);
+            for &value in row {
+                assert!(value < Q);
+            }
         }
-        
-        // Decrypt the message
-        let decrypted = decrypt(&ciphertext_bytes, &s_bytes).unwrap();
-        
-        // Verify that the decrypted message matches the original
-        assert_eq!(decrypted, message);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_expand_matrix_differs_per_seed() {
+        let a = expand_matrix(&[1u8; 32], 2, 2);
+        let b = expand_matrix(&[2u8; 32], 2, 2);
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn test_expand_matrix_round_trips_through_encode_decode_coeffs() {
+        let a = expand_matrix(&[9u8; 32], N, N);
+
+        let mut bytes = Vec::new();
+        for row in &a {
+            bytes.extend_from_slice(&encode_coeffs(row));
+        }
+
+        let decoded = decode_coeffs(&bytes, N * N).unwrap();
+        let flattened: Vec<u32> = a.into_iter().flatten().collect();
+        assert_eq!(decoded, flattened);
+    }
+}
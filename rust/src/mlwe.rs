@@ -0,0 +1,383 @@
+//! Module-LWE ring arithmetic and a minimal lattice-based KEM built on it
+//!
+//! [`crate::kem::Kem`] started out with a hash-mask placeholder core. This
+//! module provides the real mathematics behind the Kyber family of lattice
+//! schemes — polynomial-ring arithmetic over
+//! `Z_q[X]/(X^n + 1)`, centered-binomial noise sampling, and a CPA-secure
+//! encryption scheme built from them — and is now `Kem`'s actual PKE core
+//! (see `kem::pke_encrypt`/`kem::pke_decrypt`): growing
+//! `PublicKey`/`SecretKey`/`Ciphertext` past their old 64/64/128-byte sizes
+//! was the accepted cost of closing the confidentiality hole a hash-mask
+//! placeholder core left open.
+//!
+//! # Parameters
+//!
+//! `n = 256`, `q = 3329` (both taken from ML-KEM), module rank `k = 1` (a
+//! single ring element, i.e. plain Ring-LWE rather than the full matrix
+//! case), and centered-binomial noise with `eta = 2`. This is smaller than
+//! any standardized ML-KEM parameter set and is not claimed to meet a
+//! particular security level; it demonstrates the real construction at a
+//! size this crate can responsibly carry as an addition.
+
+#[cfg(not(feature = "std"))]
+use alloc::{vec, vec::Vec};
+
+use crate::hash::xof;
+
+/// Ring dimension: polynomials live in `Z_q[X] / (X^N + 1)`
+pub const N: usize = 256;
+/// Ring modulus (ML-KEM's prime: `3329 = 2^8 * 13 + 1`)
+pub const Q: i16 = 3329;
+/// Centered-binomial noise parameter
+const ETA: usize = 2;
+
+/// A polynomial in `Z_q[X] / (X^N + 1)`, coefficients reduced to `[0, Q)`
+pub type Poly = [i16; N];
+
+/// Reduce `x` into `[0, Q)`
+#[inline]
+fn reduce(x: i32) -> i16 {
+    let r = x.rem_euclid(Q as i32);
+    r as i16
+}
+
+/// Add two ring elements coefficient-wise mod `q`
+pub fn poly_add(a: &Poly, b: &Poly) -> Poly {
+    let mut out = [0i16; N];
+    for i in 0..N {
+        out[i] = reduce(a[i] as i32 + b[i] as i32);
+    }
+    out
+}
+
+/// Subtract two ring elements coefficient-wise mod `q`
+pub fn poly_sub(a: &Poly, b: &Poly) -> Poly {
+    let mut out = [0i16; N];
+    for i in 0..N {
+        out[i] = reduce(a[i] as i32 - b[i] as i32);
+    }
+    out
+}
+
+/// Multiply two ring elements via schoolbook convolution, reduced modulo
+/// `X^N + 1` (so `X^N` wraps around and negates, i.e. negacyclic)
+pub fn poly_mul(a: &Poly, b: &Poly) -> Poly {
+    let mut acc = [0i32; N];
+    for (i, &ai) in a.iter().enumerate() {
+        if ai == 0 {
+            continue;
+        }
+        for (j, &bj) in b.iter().enumerate() {
+            let product = ai as i32 * bj as i32;
+            let degree = i + j;
+            if degree < N {
+                acc[degree] += product;
+            } else {
+                // X^N == -1 (mod X^N + 1), so terms that overflow the ring
+                // wrap around to a lower degree with a sign flip.
+                acc[degree - N] -= product;
+            }
+        }
+    }
+
+    let mut out = [0i16; N];
+    for i in 0..N {
+        out[i] = reduce(acc[i]);
+    }
+    out
+}
+
+/// Expand a seed into a uniformly random ring element via rejection
+/// sampling over [`crate::hash::xof`] output
+///
+/// Mirrors how ML-KEM expands its public matrix `A` from a short seed:
+/// draws 12-bit candidates from the XOF stream and keeps those below `q`,
+/// discarding the rest so the result is uniform over `[0, q)` rather than
+/// biased toward the low end of a 12-bit range.
+pub fn expand_uniform(seed: &[u8], domain: u8) -> Poly {
+    let mut out = [0i16; N];
+    let mut filled = 0;
+    let mut block = 0u8;
+
+    // Each accepted candidate needs 12 bits and roughly 3329/4096 ≈ 81% of
+    // candidates are accepted, so oversample generously per round rather
+    // than pulling the stream one candidate at a time.
+    while filled < N {
+        let mut label = Vec::with_capacity(seed.len() + 2);
+        label.push(domain);
+        label.extend_from_slice(seed);
+        label.push(block);
+
+        let mut stream = vec![0u8; 3 * (N - filled)];
+        xof(&label, &mut stream);
+
+        for chunk in stream.chunks_exact(3) {
+            if filled >= N {
+                break;
+            }
+            let d1 = u16::from(chunk[0]) | (u16::from(chunk[1] & 0x0F) << 8);
+            let d2 = (u16::from(chunk[1]) >> 4) | (u16::from(chunk[2]) << 4);
+            if d1 < Q as u16 {
+                out[filled] = d1 as i16;
+                filled += 1;
+            }
+            if filled < N && d2 < Q as u16 {
+                out[filled] = d2 as i16;
+                filled += 1;
+            }
+        }
+
+        block = block.wrapping_add(1);
+    }
+
+    out
+}
+
+/// Sample a centered-binomial-noise ring element from `seed`
+///
+/// Each coefficient is `sum(bit_0..bit_eta) - sum(bit_eta..bit_2eta)` of
+/// independent random bits, the standard way lattice KEMs draw
+/// small, roughly-Gaussian secret and error terms cheaply.
+pub fn sample_cbd(seed: &[u8], domain: u8) -> Poly {
+    let mut label = Vec::with_capacity(seed.len() + 1);
+    label.push(domain);
+    label.extend_from_slice(seed);
+
+    let mut randomness = vec![0u8; (N * 2 * ETA) / 8];
+    xof(&label, &mut randomness);
+
+    let mut bits = Vec::with_capacity(randomness.len() * 8);
+    for byte in &randomness {
+        for bit in 0..8 {
+            bits.push((byte >> bit) & 1);
+        }
+    }
+
+    let mut out = [0i16; N];
+    for i in 0..N {
+        let base = i * 2 * ETA;
+        let a: i16 = bits[base..base + ETA].iter().map(|&b| i16::from(b)).sum();
+        let b: i16 = bits[base + ETA..base + 2 * ETA]
+            .iter()
+            .map(|&b| i16::from(b))
+            .sum();
+        out[i] = reduce((a - b) as i32);
+    }
+    out
+}
+
+/// Encode a 32-byte (256-bit) message as a ring element: each bit lifts to
+/// either `0` or `round(q / 2)`, spreading one message bit across the full
+/// range of a coefficient so small decryption noise doesn't flip it
+pub fn encode_message(message: &[u8; 32]) -> Poly {
+    let mut out = [0i16; N];
+    for (i, coefficient) in out.iter_mut().enumerate() {
+        let byte = message[i / 8];
+        let bit = (byte >> (i % 8)) & 1;
+        *coefficient = if bit == 1 { (Q + 1) / 2 } else { 0 };
+    }
+    out
+}
+
+/// Inverse of [`encode_message`]: recover each message bit from whichever
+/// of `0` or `round(q / 2)` a (possibly noisy) coefficient is closer to
+pub fn decode_message(poly: &Poly) -> [u8; 32] {
+    let mut message = [0u8; 32];
+    for (i, &coefficient) in poly.iter().enumerate() {
+        let distance_from_half = (coefficient - (Q + 1) / 2).unsigned_abs();
+        let distance_from_zero = core::cmp::min(coefficient, Q - coefficient) as u16;
+        if distance_from_half < distance_from_zero {
+            message[i / 8] |= 1 << (i % 8);
+        }
+    }
+    message
+}
+
+/// A Ring-LWE public key: the XOF seed for `A` plus `t = a*s + e`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PublicKey {
+    seed: [u8; 32],
+    t: Poly,
+}
+
+/// A Ring-LWE secret key: the small secret ring element `s`
+#[derive(Debug, Clone)]
+pub struct SecretKey {
+    s: Poly,
+}
+
+/// A Ring-LWE ciphertext encrypting a 32-byte message: `(u, v)`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Ciphertext {
+    u: Poly,
+    v: Poly,
+}
+
+/// Generate a Ring-LWE key pair from a 32-byte seed
+///
+/// Expands `a` from `seed` via [`expand_uniform`], draws small secret and
+/// error terms `s, e` via [`sample_cbd`] (each independently
+/// domain-separated from `seed`), and sets the public key to
+/// `t = a*s + e`.
+pub fn keygen(seed: &[u8; 32]) -> (PublicKey, SecretKey) {
+    let a = expand_uniform(seed, 0);
+    let s = sample_cbd(seed, 1);
+    let e = sample_cbd(seed, 2);
+    let t = poly_add(&poly_mul(&a, &s), &e);
+
+    (PublicKey { seed: *seed, t }, SecretKey { s })
+}
+
+/// Encrypt a 32-byte message under `public_key`, using `coins` as the
+/// randomness seed for the ephemeral secret and error terms
+///
+/// `u = a*r + e1`, `v = t*r + e2 + encode(message)`; see [`decrypt`] for
+/// the matching recovery step.
+pub fn encrypt(public_key: &PublicKey, message: &[u8; 32], coins: &[u8; 32]) -> Ciphertext {
+    let a = expand_uniform(&public_key.seed, 0);
+    let r = sample_cbd(coins, 1);
+    let e1 = sample_cbd(coins, 2);
+    let e2 = sample_cbd(coins, 3);
+
+    let u = poly_add(&poly_mul(&a, &r), &e1);
+    let v = poly_add(
+        &poly_add(&poly_mul(&public_key.t, &r), &e2),
+        &encode_message(message),
+    );
+
+    Ciphertext { u, v }
+}
+
+/// Decrypt `ciphertext` with `secret_key`, recovering the original message
+/// up to the scheme's decryption-noise tolerance
+///
+/// Computes `v - s*u = encode(message) + (small noise)` and rounds each
+/// coefficient back to the nearer of `0`/`round(q / 2)`.
+pub fn decrypt(secret_key: &SecretKey, ciphertext: &Ciphertext) -> [u8; 32] {
+    let noisy = poly_sub(&ciphertext.v, &poly_mul(&secret_key.s, &ciphertext.u));
+    decode_message(&noisy)
+}
+
+impl PublicKey {
+    /// Reassemble a public key from its seed and `t` ring element, e.g. when
+    /// decoding one from a wire format instead of running [`keygen`]
+    #[inline(always)]
+    pub fn from_parts(seed: [u8; 32], t: Poly) -> Self {
+        PublicKey { seed, t }
+    }
+
+    /// The XOF seed this key's matrix element `a` was expanded from
+    #[inline(always)]
+    pub fn seed(&self) -> &[u8; 32] {
+        &self.seed
+    }
+
+    /// The public ring element `t = a*s + e`
+    #[inline(always)]
+    pub fn t(&self) -> &Poly {
+        &self.t
+    }
+}
+
+impl SecretKey {
+    /// The secret ring element `s`
+    #[inline(always)]
+    pub fn s(&self) -> &Poly {
+        &self.s
+    }
+}
+
+impl Ciphertext {
+    /// Reassemble a ciphertext from its `u`/`v` ring elements, e.g. when
+    /// decoding one from a wire format instead of running [`encrypt`]
+    #[inline(always)]
+    pub fn from_parts(u: Poly, v: Poly) -> Self {
+        Ciphertext { u, v }
+    }
+
+    /// The ciphertext's `u` ring element
+    #[inline(always)]
+    pub fn u(&self) -> &Poly {
+        &self.u
+    }
+
+    /// The ciphertext's `v` ring element
+    #[inline(always)]
+    pub fn v(&self) -> &Poly {
+        &self.v
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_poly_mul_identity() {
+        let mut one = [0i16; N];
+        one[0] = 1;
+        let a = expand_uniform(b"poly-mul-identity-seed", 0);
+
+        assert_eq!(poly_mul(&a, &one), a);
+    }
+
+    #[test]
+    fn test_poly_add_sub_round_trip() {
+        let a = expand_uniform(b"poly-add-sub-a", 0);
+        let b = expand_uniform(b"poly-add-sub-b", 0);
+
+        assert_eq!(poly_sub(&poly_add(&a, &b), &b), a);
+    }
+
+    #[test]
+    fn test_sample_cbd_is_small_and_deterministic() {
+        let poly = sample_cbd(b"cbd-seed", 0);
+        for &coefficient in &poly {
+            // A coefficient of magnitude > ETA can only arise from the mod-q
+            // reduction wrapping a negative value, i.e. it must be within
+            // ETA of 0 or of Q.
+            assert!(coefficient as usize <= ETA || (Q - coefficient) as usize <= ETA);
+        }
+
+        assert_eq!(sample_cbd(b"cbd-seed", 0), poly);
+        assert_ne!(sample_cbd(b"cbd-seed", 1), poly);
+    }
+
+    #[test]
+    fn test_message_encode_decode_round_trip() {
+        let message = *b"0123456789abcdef0123456789abcdef";
+        let message: [u8; 32] = message[..32].try_into().unwrap();
+
+        let encoded = encode_message(&message);
+        assert_eq!(decode_message(&encoded), message);
+    }
+
+    #[test]
+    fn test_keygen_encrypt_decrypt_round_trip() {
+        let seed = [42u8; 32];
+        let (public_key, secret_key) = keygen(&seed);
+
+        for trial in 0u8..8 {
+            let mut message = [0u8; 32];
+            message[0] = trial;
+            message[17] = trial.wrapping_mul(7);
+
+            let coins = [trial; 32];
+            let ciphertext = encrypt(&public_key, &message, &coins);
+            let decrypted = decrypt(&secret_key, &ciphertext);
+
+            assert_eq!(decrypted, message, "round trip failed for trial {trial}");
+        }
+    }
+
+    #[test]
+    fn test_different_keys_decrypt_differently() {
+        let (public_key_a, _) = keygen(&[1u8; 32]);
+        let (_, secret_key_b) = keygen(&[2u8; 32]);
+
+        let message = [7u8; 32];
+        let ciphertext = encrypt(&public_key_a, &message, &[9u8; 32]);
+
+        assert_ne!(decrypt(&secret_key_b, &ciphertext), message);
+    }
+}
@@ -0,0 +1,310 @@
+//! Number-theoretic transform (NTT) for negacyclic polynomial multiplication
+//!
+//! [`crate::lwe`]'s matrix `A` is an unstructured `N x N` matrix, so its
+//! matrix-vector product can't be sped up by a transform — there's no
+//! convolution structure to exploit. A negacyclic *polynomial* product in
+//! `Z_Q[X]/(X^N + 1)`, on the other hand, is exactly the structure an NTT
+//! accelerates: it turns an `O(N^2)` schoolbook convolution into `O(N log
+//! N)` by evaluating both operands at the `2N`-th roots of unity, which
+//! diagonalizes the product into an elementwise one.
+//!
+//! This module targets [`crate::params::N`]/[`crate::params::Q`]
+//! (`1024`/`65537`) rather than [`crate::mlwe`]'s ring (`n = 256`, `q =
+//! 3329`), because `Q = 65537` is a Fermat prime whose order-`65536`
+//! multiplicative group is divisible by `2N = 2048`, giving a genuine
+//! primitive `2N`-th root of unity and a *complete* NTT — every coefficient
+//! splits all the way down to an independent scalar, so the pointwise step
+//! really is a single [`mod_mul`] per position. `mlwe`'s `q = 3329` has `q -
+//! 1 ≡ 256 (mod 512)`, so it only supports an *incomplete* NTT (stopping at
+//! degree-2 factors, à la ML-KEM's base multiplication) — a different,
+//! more involved construction than what's implemented here. This is an
+//! additive primitive for negacyclic-convolution-shaped arithmetic, not yet
+//! wired into `lwe`'s matrix-vector path.
+//!
+//! `ntt_forward`/`ntt_inverse` fold the negacyclic twist (multiplying
+//! coefficient `i` by `psi^i`/`psi^-i`, for `psi` a primitive `2N`-th root)
+//! around a standard radix-2 Cooley–Tukey transform keyed on `omega =
+//! psi^2`, a primitive `N`-th root, so callers never see the intermediate
+//! cyclic representation. [`poly_mul_ntt`] composes both directions around
+//! a pointwise product to give the full negacyclic convolution.
+//!
+//! # Status: not wired into any polynomial path yet
+//!
+//! No scheme in this crate multiplies over `Z_{65537}[X]/(X^1024 + 1)`
+//! today, so [`poly_mul_ntt`] is currently dead code outside its own tests
+//! against the schoolbook reference below, not a drop-in replacement for
+//! any existing `O(N^2)` loop — [`crate::mlwe::poly_mul`] is the crate's one
+//! schoolbook negacyclic multiply, and it targets the smaller, NTT-friendly
+//! ring explained above, where only the more involved incomplete-NTT
+//! construction applies. This module stands on its own until a future
+//! scheme adopts `Q = 65537`/`N = 1024` and can use it directly.
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use crate::params::{N, Q};
+
+/// A generator of `Z_Q^*`. `Q` is prime, so this exists; for the Fermat
+/// prime `Q = 65537` the group has order `65536 = 2^16`, and `3` is
+/// verified (by [`NttTables::build`]'s debug assertion) to generate the
+/// full group rather than a smaller power-of-two subgroup.
+const PRIMITIVE_ROOT: u64 = 3;
+
+#[inline(always)]
+fn mod_add(a: u32, b: u32) -> u32 {
+    let sum = a + b;
+    if sum >= Q { sum - Q } else { sum }
+}
+
+#[inline(always)]
+fn mod_sub(a: u32, b: u32) -> u32 {
+    if a >= b { a - b } else { a + Q - b }
+}
+
+#[inline(always)]
+fn mod_mul(a: u32, b: u32) -> u32 {
+    ((a as u64 * b as u64) % (Q as u64)) as u32
+}
+
+fn mod_pow(base: u64, exp: u64) -> u64 {
+    let modulus = Q as u64;
+    let mut result = 1u64;
+    let mut base = base % modulus;
+    let mut exp = exp;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = result * base % modulus;
+        }
+        exp >>= 1;
+        base = base * base % modulus;
+    }
+    result
+}
+
+/// Modular inverse via Fermat's little theorem (`Q` is prime).
+fn mod_inv(a: u64) -> u64 {
+    mod_pow(a, Q as u64 - 2)
+}
+
+fn reverse_bits(mut x: usize, bits: u32) -> usize {
+    let mut result = 0;
+    for _ in 0..bits {
+        result = (result << 1) | (x & 1);
+        x >>= 1;
+    }
+    result
+}
+
+/// Precomputed twiddle tables for the negacyclic NTT over `Z_Q[X]/(X^N +
+/// 1)`: powers of the primitive `2N`-th root `psi` (and its inverse), plus
+/// `N`'s modular inverse for [`ntt_inverse`]'s final scaling.
+struct NttTables {
+    /// `psi_powers[i] = psi^i mod Q`, the forward negacyclic twist.
+    psi_powers: Vec<u32>,
+    /// `psi_inv_powers[i] = psi^-i mod Q`, the inverse negacyclic untwist.
+    psi_inv_powers: Vec<u32>,
+    /// `omega = psi^2`, the primitive `N`-th root the cyclic core NTT uses.
+    omega: u32,
+    /// `omega^-1 mod Q`.
+    omega_inv: u32,
+    /// `N^-1 mod Q`, applied once at the end of [`ntt_inverse`].
+    n_inv: u32,
+}
+
+impl NttTables {
+    fn build() -> Self {
+        debug_assert_eq!(
+            (Q as u64 - 1) % (2 * N as u64),
+            0,
+            "Q must satisfy Q \u{2261} 1 (mod 2N) for a complete negacyclic NTT"
+        );
+        debug_assert_ne!(
+            mod_pow(PRIMITIVE_ROOT, (Q as u64 - 1) / 2),
+            1,
+            "PRIMITIVE_ROOT must generate all of Z_Q^*, not a proper subgroup"
+        );
+
+        let psi = mod_pow(PRIMITIVE_ROOT, (Q as u64 - 1) / (2 * N as u64)) as u32;
+        let psi_inv = mod_inv(psi as u64) as u32;
+        let omega = mod_mul(psi, psi);
+        let omega_inv = mod_inv(omega as u64) as u32;
+        let n_inv = mod_inv(N as u64) as u32;
+
+        let mut psi_powers = Vec::with_capacity(N);
+        let mut psi_inv_powers = Vec::with_capacity(N);
+        let (mut acc, mut acc_inv) = (1u32, 1u32);
+        for _ in 0..N {
+            psi_powers.push(acc);
+            psi_inv_powers.push(acc_inv);
+            acc = mod_mul(acc, psi);
+            acc_inv = mod_mul(acc_inv, psi_inv);
+        }
+
+        Self { psi_powers, psi_inv_powers, omega, omega_inv, n_inv }
+    }
+}
+
+/// The process-wide NTT tables, built once and cached for the remainder of
+/// the process's lifetime.
+#[cfg(feature = "std")]
+fn ntt_tables() -> &'static NttTables {
+    static TABLES: std::sync::OnceLock<NttTables> = std::sync::OnceLock::new();
+    TABLES.get_or_init(NttTables::build)
+}
+
+/// The NTT tables. Without `std` there is nowhere to cache them, so this
+/// just rebuilds them, which is cheap relative to the transform itself.
+#[cfg(not(feature = "std"))]
+fn ntt_tables() -> NttTables {
+    NttTables::build()
+}
+
+/// In-place radix-2 Cooley–Tukey NTT over `Z_Q` with primitive `N`-th root
+/// `root`: bit-reverse-permutes `a`, then combines adjacent blocks bottom-up
+/// with doubling stage lengths, leaving the result in natural (not
+/// bit-reversed) order.
+fn cyclic_ntt(a: &mut [u32], root: u32) {
+    let bits = N.trailing_zeros();
+    for i in 0..N {
+        let j = reverse_bits(i, bits);
+        if j > i {
+            a.swap(i, j);
+        }
+    }
+
+    let mut length = 2;
+    while length <= N {
+        let half = length / 2;
+        let stage_root = mod_pow(root as u64, (N / length) as u64) as u32;
+        let mut start = 0;
+        while start < N {
+            let mut twiddle = 1u32;
+            for j in 0..half {
+                let u = a[start + j];
+                let v = mod_mul(a[start + j + half], twiddle);
+                a[start + j] = mod_add(u, v);
+                a[start + j + half] = mod_sub(u, v);
+                twiddle = mod_mul(twiddle, stage_root);
+            }
+            start += length;
+        }
+        length *= 2;
+    }
+}
+
+/// Forward negacyclic NTT: twist `coeffs` by powers of `psi`, then run the
+/// cyclic core transform, so `poly_mul_ntt`'s pointwise product corresponds
+/// to convolution in `Z_Q[X]/(X^N + 1)` rather than `Z_Q[X]/(X^N - 1)`.
+pub fn ntt_forward(coeffs: &mut [u32; N]) {
+    let table = ntt_tables();
+    for (i, coeff) in coeffs.iter_mut().enumerate() {
+        *coeff = mod_mul(*coeff, table.psi_powers[i]);
+    }
+    cyclic_ntt(coeffs, table.omega);
+}
+
+/// Inverse negacyclic NTT: undo [`ntt_forward`] — the cyclic core transform
+/// keyed on `omega^-1`, scaled by `N^-1`, then untwisted by powers of
+/// `psi^-1`.
+pub fn ntt_inverse(coeffs: &mut [u32; N]) {
+    let table = ntt_tables();
+    cyclic_ntt(coeffs, table.omega_inv);
+    for (i, coeff) in coeffs.iter_mut().enumerate() {
+        *coeff = mod_mul(mod_mul(*coeff, table.n_inv), table.psi_inv_powers[i]);
+    }
+}
+
+/// Multiply `a` and `b` as elements of `Z_Q[X]/(X^N + 1)` in `O(N log N)`:
+/// forward-transform both operands, multiply pointwise, inverse-transform.
+/// Equivalent to (but asymptotically faster than) the schoolbook negacyclic
+/// convolution this is tested against below.
+pub fn poly_mul_ntt(a: &[u32; N], b: &[u32; N]) -> [u32; N] {
+    let mut ta = *a;
+    let mut tb = *b;
+    ntt_forward(&mut ta);
+    ntt_forward(&mut tb);
+
+    let mut product = [0u32; N];
+    for i in 0..N {
+        product[i] = mod_mul(ta[i], tb[i]);
+    }
+
+    ntt_inverse(&mut product);
+    product
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand_chacha::ChaCha20Rng;
+    use rand_core::{RngCore, SeedableRng};
+
+    /// Schoolbook negacyclic convolution (`X^N = -1`), the `O(N^2)`
+    /// reference `poly_mul_ntt` is checked against.
+    fn poly_mul_schoolbook(a: &[u32; N], b: &[u32; N]) -> [u32; N] {
+        let mut result = [0u32; N];
+        for i in 0..N {
+            for j in 0..N {
+                let product = mod_mul(a[i], b[j]);
+                let mut index = i + j;
+                let wrapped = index >= N;
+                if wrapped {
+                    index -= N;
+                }
+                let term = if wrapped { mod_sub(0, product) } else { product };
+                result[index] = mod_add(result[index], term);
+            }
+        }
+        result
+    }
+
+    fn random_poly<R: RngCore>(rng: &mut R) -> [u32; N] {
+        let mut poly = [0u32; N];
+        for coeff in poly.iter_mut() {
+            *coeff = rng.next_u32() % Q;
+        }
+        poly
+    }
+
+    #[test]
+    fn test_ntt_roundtrip_is_identity() {
+        let mut rng = ChaCha20Rng::seed_from_u64(1);
+        let original = random_poly(&mut rng);
+
+        let mut transformed = original;
+        ntt_forward(&mut transformed);
+        ntt_inverse(&mut transformed);
+
+        assert_eq!(transformed, original);
+    }
+
+    #[test]
+    fn test_poly_mul_ntt_matches_schoolbook() {
+        let mut rng = ChaCha20Rng::seed_from_u64(2);
+        for _ in 0..5 {
+            let a = random_poly(&mut rng);
+            let b = random_poly(&mut rng);
+
+            assert_eq!(poly_mul_ntt(&a, &b), poly_mul_schoolbook(&a, &b));
+        }
+    }
+
+    #[test]
+    fn test_poly_mul_ntt_by_one_is_identity() {
+        let mut rng = ChaCha20Rng::seed_from_u64(3);
+        let a = random_poly(&mut rng);
+        let mut one = [0u32; N];
+        one[0] = 1;
+
+        assert_eq!(poly_mul_ntt(&a, &one), a);
+    }
+
+    #[test]
+    fn test_poly_mul_ntt_is_commutative() {
+        let mut rng = ChaCha20Rng::seed_from_u64(4);
+        let a = random_poly(&mut rng);
+        let b = random_poly(&mut rng);
+
+        assert_eq!(poly_mul_ntt(&a, &b), poly_mul_ntt(&b, &a));
+    }
+}
@@ -0,0 +1,493 @@
+//! Long-lived authenticated session channel over [`crate::kem`]
+//!
+//! One-shot `Kem::encapsulate`/`decapsulate` gives a single shared secret;
+//! this module turns that into a channel that can carry many messages.  The
+//! initiator picks a responder public key out of a [`TrustedKeySet`] (key
+//! pinning rather than a certificate chain) and runs a single encapsulation
+//! against it; both sides then derive directional send/receive keys from
+//! the shared secret via [`crate::kem::SharedSecret::derive_key`]. Every
+//! sealed [`Frame`] carries an explicit 64-bit sequence number so the
+//! receiver can reject replays and out-of-order duplicates with a sliding
+//! window, while still tolerating reordering and drops. Because only the
+//! initiator holds the responder's public key, only the initiator side can
+//! trigger a rekey — a fresh encapsulation folded into the existing key
+//! schedule — which it does automatically once a configurable message
+//! count or byte volume has been sealed, so a long session never leans on
+//! the same key material indefinitely.
+//!
+//! Rekeying is only meaningful because [`Kem::decapsulate`] genuinely needs
+//! the responder's secret key to recover the fresh shared secret folded
+//! into the schedule — a responder that can't decapsulate correctly folds
+//! in the wrong material and every subsequent frame fails authentication
+//! (see `test_rekey_requires_correct_secret_key`), rather than rekeying
+//! being a no-op over public data.
+
+#[cfg(not(feature = "std"))]
+use alloc::{string::ToString, vec, vec::Vec};
+
+use crate::error::{Result, TopayzError};
+use crate::hash::{xof, Hash};
+use crate::kem::{Ciphertext, Kem, PublicKey, SecretKey, SharedSecret};
+
+/// Width in messages of the replay-detection sliding window.
+const REPLAY_WINDOW_BITS: u64 = 1024;
+const REPLAY_WINDOW_WORDS: usize = (REPLAY_WINDOW_BITS / 64) as usize;
+
+/// Default number of sealed messages before [`Session::seal`] rekeys.
+pub const DEFAULT_REKEY_AFTER_MESSAGES: u64 = 10_000;
+/// Default number of sealed plaintext bytes before [`Session::seal`] rekeys.
+pub const DEFAULT_REKEY_AFTER_BYTES: u64 = 64 * 1024 * 1024;
+
+/// Length in bytes of the authentication tag appended to each frame's ciphertext.
+const TAG_LENGTH: usize = 64;
+
+/// A set of responder public keys an initiator is willing to open a
+/// [`Session`] to — key pinning in place of a certificate authority.
+#[derive(Debug, Clone, Default)]
+pub struct TrustedKeySet {
+    keys: Vec<PublicKey>,
+}
+
+impl TrustedKeySet {
+    /// Build a trusted set from a list of public keys.
+    pub fn new(keys: Vec<PublicKey>) -> Self {
+        Self { keys }
+    }
+
+    /// Whether `key` is a member of this set.
+    pub fn contains(&self, key: &PublicKey) -> bool {
+        self.keys.iter().any(|trusted| trusted.equals(key))
+    }
+}
+
+/// Which side of the handshake a [`Session`] is playing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Role {
+    Initiator,
+    Responder,
+}
+
+/// A sealed message ready to send over an unauthenticated transport.
+#[derive(Debug, Clone)]
+pub struct Frame {
+    /// Monotonically increasing per-session counter; also mixed into the
+    /// keystream and the authentication tag.
+    pub sequence: u64,
+    /// Present when this frame carries a rekey: a fresh encapsulation the
+    /// receiver must decapsulate and fold into its key schedule before
+    /// authenticating the rest of the frame.
+    pub rekey: Option<Ciphertext>,
+    /// Masked plaintext with the authentication tag appended.
+    pub ciphertext: Vec<u8>,
+}
+
+/// Sliding-window replay detector: tracks the highest sequence number seen
+/// and a 1024-bit bitmap of which of the preceding counters were already
+/// accepted, so drops and reordering are tolerated but duplicates and
+/// too-old frames are not.
+#[derive(Debug, Clone)]
+struct ReplayWindow {
+    initialized: bool,
+    highest: u64,
+    bitmap: [u64; REPLAY_WINDOW_WORDS],
+}
+
+impl ReplayWindow {
+    fn new() -> Self {
+        Self {
+            initialized: false,
+            highest: 0,
+            bitmap: [0u64; REPLAY_WINDOW_WORDS],
+        }
+    }
+
+    /// Record `sequence` as accepted if it hasn't been seen before and
+    /// isn't too old, returning whether it was accepted.
+    fn accept(&mut self, sequence: u64) -> bool {
+        if !self.initialized {
+            self.initialized = true;
+            self.highest = sequence;
+            self.set_bit(0);
+            return true;
+        }
+
+        if sequence > self.highest {
+            self.shift_left(sequence - self.highest);
+            self.highest = sequence;
+            self.set_bit(0);
+            true
+        } else {
+            let age = self.highest - sequence;
+            if age >= REPLAY_WINDOW_BITS || self.test_bit(age) {
+                false
+            } else {
+                self.set_bit(age);
+                true
+            }
+        }
+    }
+
+    fn shift_left(&mut self, shift: u64) {
+        if shift >= REPLAY_WINDOW_BITS {
+            self.bitmap = [0u64; REPLAY_WINDOW_WORDS];
+            return;
+        }
+
+        let word_shift = (shift / 64) as usize;
+        let bit_shift = (shift % 64) as u32;
+        let mut shifted = [0u64; REPLAY_WINDOW_WORDS];
+
+        for i in (0..REPLAY_WINDOW_WORDS).rev() {
+            if i < word_shift {
+                continue;
+            }
+            let src = i - word_shift;
+            let mut value = self.bitmap[src] << bit_shift;
+            if bit_shift > 0 && src > 0 {
+                value |= self.bitmap[src - 1] >> (64 - bit_shift);
+            }
+            shifted[i] = value;
+        }
+
+        self.bitmap = shifted;
+    }
+
+    fn set_bit(&mut self, index: u64) {
+        self.bitmap[(index / 64) as usize] |= 1u64 << (index % 64);
+    }
+
+    fn test_bit(&self, index: u64) -> bool {
+        (self.bitmap[(index / 64) as usize] >> (index % 64)) & 1 == 1
+    }
+}
+
+/// A long-lived, replay-resistant, self-rekeying channel over a single KEM
+/// handshake; see the module documentation for the overall design.
+pub struct Session {
+    role: Role,
+    send_key: Hash,
+    recv_key: Hash,
+    send_sequence: u64,
+    replay: ReplayWindow,
+    messages_since_rekey: u64,
+    bytes_since_rekey: u64,
+    rekey_after_messages: u64,
+    rekey_after_bytes: u64,
+    /// Only set for [`Role::Initiator`], which alone holds the public key
+    /// a rekey can be encapsulated against.
+    peer_public_key: Option<PublicKey>,
+    /// Only set for [`Role::Responder`], which alone can decapsulate a
+    /// rekey ciphertext the initiator sends.
+    local_secret_key: Option<SecretKey>,
+}
+
+impl Session {
+    /// Start a session as the initiator: validates `responder_public_key`
+    /// against `trusted`, encapsulates a fresh shared secret against it, and
+    /// returns the session alongside the ciphertext the responder needs to
+    /// call [`Session::respond`].
+    pub fn initiate(
+        trusted: &TrustedKeySet,
+        responder_public_key: &PublicKey,
+    ) -> Result<(Session, Ciphertext)> {
+        if !trusted.contains(responder_public_key) {
+            return Err(TopayzError::InvalidKey(
+                "Responder public key is not in the trusted key set".to_string(),
+            ));
+        }
+
+        let (ciphertext, shared_secret) = Kem::encapsulate(responder_public_key);
+        let session = Session::from_shared_secret(
+            Role::Initiator,
+            &shared_secret,
+            None,
+            Some(responder_public_key.clone()),
+        );
+        Ok((session, ciphertext))
+    }
+
+    /// Complete the handshake as the responder: decapsulates `ciphertext`
+    /// with `secret_key` and derives the matching session.
+    pub fn respond(secret_key: &SecretKey, ciphertext: &Ciphertext) -> Result<Session> {
+        let shared_secret = Kem::decapsulate(secret_key, ciphertext);
+        Ok(Session::from_shared_secret(
+            Role::Responder,
+            &shared_secret,
+            Some(secret_key.clone()),
+            None,
+        ))
+    }
+
+    fn from_shared_secret(
+        role: Role,
+        shared_secret: &SharedSecret,
+        local_secret_key: Option<SecretKey>,
+        peer_public_key: Option<PublicKey>,
+    ) -> Session {
+        let (send_key, recv_key) = Self::directional_keys(role, shared_secret);
+        Session {
+            role,
+            send_key,
+            recv_key,
+            send_sequence: 0,
+            replay: ReplayWindow::new(),
+            messages_since_rekey: 0,
+            bytes_since_rekey: 0,
+            rekey_after_messages: DEFAULT_REKEY_AFTER_MESSAGES,
+            rekey_after_bytes: DEFAULT_REKEY_AFTER_BYTES,
+            peer_public_key,
+            local_secret_key,
+        }
+    }
+
+    fn directional_keys(role: Role, shared_secret: &SharedSecret) -> (Hash, Hash) {
+        let send_label = shared_secret.derive_key(b"tpz-send");
+        let recv_label = shared_secret.derive_key(b"tpz-recv");
+        match role {
+            Role::Initiator => (send_label, recv_label),
+            Role::Responder => (recv_label, send_label),
+        }
+    }
+
+    /// Fold a freshly encapsulated shared secret into the existing key
+    /// schedule, so compromise of the old keys doesn't compromise the new
+    /// ones and vice versa.
+    fn fold_rekey(&mut self, shared_secret: &SharedSecret) {
+        let (send_label, recv_label) = Self::directional_keys(self.role, shared_secret);
+        self.send_key = Hash::combine(self.send_key.as_bytes(), send_label.as_bytes());
+        self.recv_key = Hash::combine(self.recv_key.as_bytes(), recv_label.as_bytes());
+        self.messages_since_rekey = 0;
+        self.bytes_since_rekey = 0;
+    }
+
+    /// Override the default rekey thresholds (message count, byte volume).
+    pub fn set_rekey_thresholds(&mut self, after_messages: u64, after_bytes: u64) {
+        self.rekey_after_messages = after_messages;
+        self.rekey_after_bytes = after_bytes;
+    }
+
+    fn needs_rekey(&self) -> bool {
+        self.messages_since_rekey >= self.rekey_after_messages
+            || self.bytes_since_rekey >= self.rekey_after_bytes
+    }
+
+    /// Seal `plaintext` into a [`Frame`], automatically rekeying first if
+    /// the configured message-count or byte-volume threshold has been
+    /// reached. Only the initiator side can rekey, since only it holds the
+    /// peer's public key to encapsulate against.
+    pub fn seal(&mut self, plaintext: &[u8]) -> Result<Frame> {
+        let rekey = if self.role == Role::Initiator && self.needs_rekey() {
+            let peer_public_key = self.peer_public_key.as_ref().ok_or_else(|| {
+                TopayzError::CryptoError("Initiator session is missing its peer public key".to_string())
+            })?;
+            let (ciphertext, shared_secret) = Kem::encapsulate(peer_public_key);
+            self.fold_rekey(&shared_secret);
+            Some(ciphertext)
+        } else {
+            None
+        };
+
+        let sequence = self.send_sequence;
+        self.send_sequence += 1;
+        self.messages_since_rekey += 1;
+        self.bytes_since_rekey += plaintext.len() as u64;
+
+        let mut ciphertext = plaintext.to_vec();
+        apply_keystream(&self.send_key, sequence, &mut ciphertext);
+
+        let tag = compute_tag(&self.send_key, sequence, rekey.as_ref(), &ciphertext);
+        ciphertext.extend_from_slice(tag.as_bytes());
+
+        Ok(Frame {
+            sequence,
+            rekey,
+            ciphertext,
+        })
+    }
+
+    /// Open a [`Frame`] produced by the peer's [`Session::seal`].
+    ///
+    /// Decapsulates and folds in `frame.rekey` first if present (only
+    /// meaningful for a responder session, since only it holds the secret
+    /// key to decapsulate one), then rejects the frame if its sequence
+    /// number is a replay or has fallen outside the sliding window, or if
+    /// the authentication tag doesn't match.
+    pub fn open(&mut self, frame: &Frame) -> Result<Vec<u8>> {
+        if let Some(rekey_ciphertext) = &frame.rekey {
+            let secret_key = self.local_secret_key.as_ref().ok_or_else(|| {
+                TopayzError::CryptoError(
+                    "Responder session is missing its secret key to decapsulate a rekey".to_string(),
+                )
+            })?;
+            let shared_secret = Kem::decapsulate(secret_key, rekey_ciphertext);
+            self.fold_rekey(&shared_secret);
+        }
+
+        if frame.ciphertext.len() < TAG_LENGTH {
+            return Err(TopayzError::InvalidInput(
+                "Frame ciphertext is too short to contain an authentication tag".to_string(),
+            ));
+        }
+        let (body, tag) = frame.ciphertext.split_at(frame.ciphertext.len() - TAG_LENGTH);
+
+        let expected_tag = compute_tag(&self.recv_key, frame.sequence, frame.rekey.as_ref(), body);
+        if !crate::utils::constant_time_eq(expected_tag.as_bytes(), tag) {
+            return Err(TopayzError::CryptoError(
+                "Session frame failed authentication".to_string(),
+            ));
+        }
+
+        if !self.replay.accept(frame.sequence) {
+            return Err(TopayzError::CryptoError(
+                "Session frame rejected by replay window".to_string(),
+            ));
+        }
+
+        let mut plaintext = body.to_vec();
+        apply_keystream(&self.recv_key, frame.sequence, &mut plaintext);
+        Ok(plaintext)
+    }
+}
+
+/// XOR `data` in place with an XOF-expanded keystream keyed by `key` and
+/// the frame's `sequence` number.
+fn apply_keystream(key: &Hash, sequence: u64, data: &mut [u8]) {
+    let mut seed = Vec::with_capacity(64 + 8);
+    seed.extend_from_slice(key.as_bytes());
+    seed.extend_from_slice(&sequence.to_le_bytes());
+
+    let mut keystream = vec![0u8; data.len()];
+    xof(&seed, &mut keystream);
+
+    for (byte, ks) in data.iter_mut().zip(keystream.iter()) {
+        *byte ^= ks;
+    }
+}
+
+/// Compute the authentication tag over the sequence number, the optional
+/// rekey ciphertext, and the masked ciphertext. After the fixed-width
+/// sequence number, the two variable-length fields are each length-prefixed
+/// (little-endian `u64`) before hashing, mirroring
+/// `crate::dem::compute_siv_tag`, so a tag over one split of the fields can't
+/// collide with a tag over some other split of the same bytes.
+fn compute_tag(key: &Hash, sequence: u64, rekey: Option<&Ciphertext>, ciphertext: &[u8]) -> Hash {
+    let rekey_bytes: &[u8] = rekey.map(|c| c.as_bytes().as_slice()).unwrap_or(&[]);
+
+    let mut data = Vec::with_capacity(8 + 8 + rekey_bytes.len() + 8 + ciphertext.len());
+    data.extend_from_slice(&sequence.to_le_bytes());
+    for part in [rekey_bytes, ciphertext] {
+        data.extend_from_slice(&(part.len() as u64).to_le_bytes());
+        data.extend_from_slice(part);
+    }
+    Hash::hmac(key.as_bytes(), &data)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn handshake() -> (Session, Session) {
+        let (responder_public, responder_secret) = Kem::keygen();
+        let trusted = TrustedKeySet::new(vec![responder_public.clone()]);
+
+        let (initiator, ciphertext) = Session::initiate(&trusted, &responder_public).unwrap();
+        let responder = Session::respond(&responder_secret, &ciphertext).unwrap();
+        (initiator, responder)
+    }
+
+    #[test]
+    fn test_seal_open_roundtrip() {
+        let (mut initiator, mut responder) = handshake();
+
+        let frame = initiator.seal(b"hello, session").unwrap();
+        let plaintext = responder.open(&frame).unwrap();
+        assert_eq!(plaintext, b"hello, session");
+    }
+
+    #[test]
+    fn test_initiate_rejects_untrusted_responder_key() {
+        let (responder_public, _responder_secret) = Kem::keygen();
+        let (other_public, _other_secret) = Kem::keygen();
+        let trusted = TrustedKeySet::new(vec![other_public]);
+
+        assert!(Session::initiate(&trusted, &responder_public).is_err());
+    }
+
+    #[test]
+    fn test_open_rejects_replayed_frame() {
+        let (mut initiator, mut responder) = handshake();
+
+        let frame = initiator.seal(b"once only").unwrap();
+        assert!(responder.open(&frame).is_ok());
+        assert!(responder.open(&frame).is_err());
+    }
+
+    #[test]
+    fn test_open_tolerates_out_of_order_frames() {
+        let (mut initiator, mut responder) = handshake();
+
+        let first = initiator.seal(b"first").unwrap();
+        let second = initiator.seal(b"second").unwrap();
+
+        assert_eq!(responder.open(&second).unwrap(), b"second");
+        assert_eq!(responder.open(&first).unwrap(), b"first");
+    }
+
+    #[test]
+    fn test_open_rejects_tampered_ciphertext() {
+        let (mut initiator, mut responder) = handshake();
+
+        let mut frame = initiator.seal(b"tamper me").unwrap();
+        let last = frame.ciphertext.len() - 1;
+        frame.ciphertext[last] ^= 0x01;
+
+        assert!(responder.open(&frame).is_err());
+    }
+
+    #[test]
+    fn test_seal_automatically_rekeys_after_message_threshold() {
+        let (mut initiator, mut responder) = handshake();
+        initiator.set_rekey_thresholds(2, u64::MAX);
+        responder.set_rekey_thresholds(2, u64::MAX);
+
+        let first = initiator.seal(b"a").unwrap();
+        assert!(first.rekey.is_none());
+        let second = initiator.seal(b"b").unwrap();
+        assert!(second.rekey.is_none());
+        let third = initiator.seal(b"c").unwrap();
+        assert!(third.rekey.is_some());
+
+        assert_eq!(responder.open(&first).unwrap(), b"a");
+        assert_eq!(responder.open(&second).unwrap(), b"b");
+        assert_eq!(responder.open(&third).unwrap(), b"c");
+
+        // Both sides folded the rekey in lockstep, so the channel keeps working.
+        let fourth = initiator.seal(b"d").unwrap();
+        assert_eq!(responder.open(&fourth).unwrap(), b"d");
+    }
+
+    #[test]
+    fn test_rekey_requires_correct_secret_key() {
+        // Swap in a responder session built from an unrelated secret key: it
+        // decapsulates the rekey ciphertext to the wrong shared secret, folds
+        // the wrong key material in, and so must fail to authenticate the
+        // very frame that carried the rekey.
+        let (responder_public, responder_secret) = Kem::keygen();
+        let (_, unrelated_secret) = Kem::keygen();
+        let trusted = TrustedKeySet::new(vec![responder_public.clone()]);
+
+        let (mut initiator, ciphertext) = Session::initiate(&trusted, &responder_public).unwrap();
+        let mut responder = Session::respond(&responder_secret, &ciphertext).unwrap();
+        let mut impostor_responder = Session::respond(&unrelated_secret, &ciphertext).unwrap();
+
+        initiator.set_rekey_thresholds(1, u64::MAX);
+        responder.set_rekey_thresholds(1, u64::MAX);
+
+        let first = initiator.seal(b"a").unwrap();
+        assert!(responder.open(&first).is_ok());
+
+        let rekeying = initiator.seal(b"b").unwrap();
+        assert!(rekeying.rekey.is_some());
+        assert!(impostor_responder.open(&rekeying).is_err());
+    }
+}
@@ -0,0 +1,368 @@
+//! Winternitz one-time signatures (WOTS) for TOPAY-Z512
+//!
+//! Validators in the integration tests simulate "signatures" with
+//! `Hash::combine`, but that gives no actual unforgeability guarantee. This
+//! module builds a real hash-based one-time signature scheme on top of the
+//! existing 512-bit [`Hash`] primitive: it is genuinely quantum-resistant
+//! (its security reduces to the hash function's preimage resistance, unlike
+//! an ECC scheme) at the cost of being usable for exactly one signature per
+//! key pair.
+//!
+//! The scheme uses Winternitz parameter `w = 4` (base-16 digits, so each
+//! digit is a nibble of the message digest). A key pair holds one secret
+//! "chain" per digit; the public key is the chains hashed to the top
+//! (`2^w - 1 = 15` times) and compressed with `Hash::concat`. Signing a
+//! message releases each chain hashed only as many times as that digit's
+//! value, plus a checksum over the digits. A forger can only hash a
+//! released value *forward*, i.e. raise a digit; raising any message digit
+//! lowers the checksum, and lowering a checksum digit would need a chain
+//! walked backward, so the checksum makes any forged digit change detectable.
+//!
+//! # One-time use
+//!
+//! **Never sign more than one message with the same [`OtsKeyPair`].** Doing
+//! so reveals enough of each chain to let an attacker interpolate a forged
+//! signature for a different message. Generate a fresh key pair per
+//! signature.
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use crate::hash::Hash;
+use crate::HASH_SIZE;
+use rand_core::{CryptoRng, RngCore};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Bits per Winternitz digit (`w`)
+const W: u32 = 4;
+/// Digit radix (`2^w`)
+const BASE: u32 = 1 << W;
+/// Maximum single-digit value (`2^w - 1`), i.e. how many times each chain is
+/// hashed to go from the secret value to the public chain end
+const MAX_DIGIT: u32 = BASE - 1;
+/// One base-16 digit per nibble of the 512-bit message digest
+const MSG_DIGITS: usize = HASH_SIZE * 2;
+/// Largest possible checksum: every message digit at its minimum (0) leaves
+/// `MAX_DIGIT` of "unused" hashes per digit
+const CHECKSUM_MAX: u32 = MAX_DIGIT * MSG_DIGITS as u32;
+/// Base-16 digits needed to represent `CHECKSUM_MAX`
+const CHECKSUM_DIGITS: usize = {
+    let checksum_bits = u32::BITS - CHECKSUM_MAX.leading_zeros();
+    ((checksum_bits + W - 1) / W) as usize
+};
+/// Total number of hash chains in a key pair
+const CHAINS: usize = MSG_DIGITS + CHECKSUM_DIGITS;
+
+/// High-performance pseudo-random number generator for chain-seed generation
+///
+/// Mirrors the `OptimizedRng` used by [`crate::keypair`] and [`crate::kem`].
+struct OptimizedRng {
+    state: [u64; 4],
+}
+
+impl OptimizedRng {
+    #[inline]
+    fn new() -> Self {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_nanos() as u64;
+
+        let state = [
+            now,
+            now.wrapping_mul(0x9E3779B97F4A7C15),
+            now.wrapping_mul(0xBF58476D1CE4E5B9),
+            now.wrapping_mul(0x94D049BB133111EB),
+        ];
+
+        Self { state }
+    }
+
+    #[inline]
+    fn next_bytes(&mut self, bytes: &mut [u8]) {
+        let mut i = 0;
+        while i < bytes.len() {
+            let random_u64 = self.next_u64();
+            let to_copy = core::cmp::min(8, bytes.len() - i);
+            bytes[i..i + to_copy].copy_from_slice(&random_u64.to_le_bytes()[..to_copy]);
+            i += to_copy;
+        }
+    }
+
+    #[inline(always)]
+    fn next_u64(&mut self) -> u64 {
+        let result = self.state[1].wrapping_mul(5).rotate_left(7).wrapping_mul(9);
+        let t = self.state[1] << 17;
+
+        self.state[2] ^= self.state[0];
+        self.state[3] ^= self.state[1];
+        self.state[1] ^= self.state[2];
+        self.state[0] ^= self.state[3];
+
+        self.state[2] ^= t;
+        self.state[3] = self.state[3].rotate_left(45);
+
+        result
+    }
+}
+
+/// Hash `value` iteratively `count` times, advancing one step up its chain
+#[inline]
+fn hash_chain(value: &[u8; HASH_SIZE], count: u32) -> [u8; HASH_SIZE] {
+    let mut current = *value;
+    for _ in 0..count {
+        current = *Hash::new(&current).as_bytes();
+    }
+    current
+}
+
+/// Split a 64-byte message digest into `MSG_DIGITS` base-16 digits, most
+/// significant nibble first
+fn digest_digits(digest: &Hash) -> Vec<u32> {
+    let mut digits = Vec::with_capacity(MSG_DIGITS);
+    for &byte in digest.as_bytes() {
+        digits.push((byte >> 4) as u32);
+        digits.push((byte & 0x0F) as u32);
+    }
+    digits
+}
+
+/// Compute the checksum digits over a message's digits: the sum of each
+/// digit's "distance" from `MAX_DIGIT`, encoded as `CHECKSUM_DIGITS` base-16
+/// digits, most significant first. Forging a signature by only increasing a
+/// message digit (the one direction a hash chain can be extended) decreases
+/// the checksum, so the checksum digits themselves would also need forging
+/// backward — which the one-way chain construction prevents.
+fn checksum_digits(message_digits: &[u32]) -> Vec<u32> {
+    let checksum: u32 = message_digits.iter().map(|&d| MAX_DIGIT - d).sum();
+
+    let mut digits = Vec::with_capacity(CHECKSUM_DIGITS);
+    digits.resize(CHECKSUM_DIGITS, 0u32);
+    let mut remaining = checksum;
+    for digit in digits.iter_mut().rev() {
+        *digit = remaining % BASE;
+        remaining /= BASE;
+    }
+    digits
+}
+
+/// All `CHAINS` digits (message digits followed by checksum digits) that a
+/// signature or verification must walk
+fn all_digits(msg: &[u8]) -> Vec<u32> {
+    let digest = Hash::new(msg);
+    let message_digits = digest_digits(&digest);
+    let checksum = checksum_digits(&message_digits);
+
+    let mut digits = message_digits;
+    digits.extend(checksum);
+    digits
+}
+
+/// A one-time signature public key: the chain ends, compressed with
+/// [`Hash::concat`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OtsPublicKey {
+    bytes: [u8; HASH_SIZE],
+}
+
+impl OtsPublicKey {
+    /// Get the public key as a byte array
+    #[inline(always)]
+    pub fn as_bytes(&self) -> &[u8; HASH_SIZE] {
+        &self.bytes
+    }
+}
+
+/// A Winternitz one-time signature: one released chain value per digit
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Signature {
+    chains: Vec<[u8; HASH_SIZE]>,
+}
+
+/// A Winternitz one-time signature key pair
+///
+/// **Sign at most one message with a given key pair** — see the module
+/// documentation for why reuse breaks the scheme's security.
+#[derive(Debug, Clone)]
+pub struct OtsKeyPair {
+    secret_chains: Vec<[u8; HASH_SIZE]>,
+    public_key: OtsPublicKey,
+}
+
+impl OtsKeyPair {
+    /// Generate a new one-time key pair
+    pub fn generate() -> Self {
+        let mut rng = OptimizedRng::new();
+        Self::generate_with(|bytes| rng.next_bytes(bytes))
+    }
+
+    /// Generate a new one-time key pair using a caller-supplied CSPRNG
+    pub fn generate_with_rng<R: RngCore + CryptoRng>(rng: &mut R) -> Self {
+        Self::generate_with(|bytes| rng.fill_bytes(bytes))
+    }
+
+    fn generate_with(mut fill: impl FnMut(&mut [u8])) -> Self {
+        let mut secret_chains = Vec::with_capacity(CHAINS);
+        for _ in 0..CHAINS {
+            let mut seed = [0u8; HASH_SIZE];
+            fill(&mut seed);
+            secret_chains.push(seed);
+        }
+
+        let public_key = Self::derive_public_key(&secret_chains);
+
+        Self {
+            secret_chains,
+            public_key,
+        }
+    }
+
+    fn derive_public_key(secret_chains: &[[u8; HASH_SIZE]]) -> OtsPublicKey {
+        let chain_ends: Vec<Hash> = secret_chains
+            .iter()
+            .map(|chain| Hash::from_bytes(hash_chain(chain, MAX_DIGIT)))
+            .collect();
+        let chain_end_refs: Vec<&Hash> = chain_ends.iter().collect();
+
+        OtsPublicKey {
+            bytes: *Hash::concat(&chain_end_refs).as_bytes(),
+        }
+    }
+
+    /// Get the public key
+    #[inline(always)]
+    pub fn public_key(&self) -> &OtsPublicKey {
+        &self.public_key
+    }
+
+    /// Sign `msg`
+    ///
+    /// Consumes `self`: a `OtsKeyPair` is only safe to sign with once, so
+    /// taking it by value prevents accidentally reusing it for a second
+    /// message.
+    pub fn sign(self, msg: &[u8]) -> Signature {
+        let digits = all_digits(msg);
+
+        let chains = self
+            .secret_chains
+            .iter()
+            .zip(digits.iter())
+            .map(|(chain, &digit)| hash_chain(chain, digit))
+            .collect();
+
+        Signature { chains }
+    }
+
+    /// Secure zero out the secret chains (for security)
+    pub fn zeroize(&mut self) {
+        for chain in self.secret_chains.iter_mut() {
+            #[cfg(feature = "zeroize")]
+            {
+                use zeroize::Zeroize;
+                chain.zeroize();
+            }
+            #[cfg(not(feature = "zeroize"))]
+            {
+                crate::utils::secure_zero(chain);
+            }
+        }
+    }
+}
+
+impl Drop for OtsKeyPair {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+/// Verify that `sig` is a valid one-time signature over `msg` under `pk`
+pub fn verify(pk: &OtsPublicKey, msg: &[u8], sig: &Signature) -> bool {
+    if sig.chains.len() != CHAINS {
+        return false;
+    }
+
+    let digits = all_digits(msg);
+
+    let chain_ends: Vec<Hash> = sig
+        .chains
+        .iter()
+        .zip(digits.iter())
+        .map(|(released, &digit)| Hash::from_bytes(hash_chain(released, MAX_DIGIT - digit)))
+        .collect();
+    let chain_end_refs: Vec<&Hash> = chain_ends.iter().collect();
+
+    let recomputed = Hash::concat(&chain_end_refs);
+    recomputed.as_bytes() == pk.as_bytes()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sign_verify_roundtrip() {
+        let keypair = OtsKeyPair::generate();
+        let public_key = keypair.public_key().clone();
+        let msg = b"transfer 10 TOPAY to Bob";
+
+        let signature = keypair.sign(msg);
+        assert!(verify(&public_key, msg, &signature));
+    }
+
+    #[test]
+    fn test_verify_rejects_wrong_message() {
+        let keypair = OtsKeyPair::generate();
+        let public_key = keypair.public_key().clone();
+
+        let signature = keypair.sign(b"transfer 10 TOPAY to Bob");
+        assert!(!verify(&public_key, b"transfer 99 TOPAY to Bob", &signature));
+    }
+
+    #[test]
+    fn test_verify_rejects_wrong_public_key() {
+        let keypair1 = OtsKeyPair::generate();
+        let keypair2 = OtsKeyPair::generate();
+        let msg = b"transfer 10 TOPAY to Bob";
+
+        let signature = keypair1.sign(msg);
+        assert!(!verify(keypair2.public_key(), msg, &signature));
+    }
+
+    #[test]
+    fn test_verify_rejects_tampered_signature() {
+        let keypair = OtsKeyPair::generate();
+        let public_key = keypair.public_key().clone();
+        let msg = b"transfer 10 TOPAY to Bob";
+
+        let mut signature = keypair.sign(msg);
+        signature.chains[0][0] ^= 0x01;
+        assert!(!verify(&public_key, msg, &signature));
+    }
+
+    #[test]
+    fn test_verify_rejects_forged_chain_extension() {
+        // Flipping a released chain value forward by one more hash step is
+        // the canonical WOTS forgery attempt; the checksum should catch it
+        // whenever it isn't caught directly by the message digit itself.
+        let keypair = OtsKeyPair::generate();
+        let public_key = keypair.public_key().clone();
+        let msg = b"transfer 10 TOPAY to Bob";
+
+        let mut signature = keypair.sign(msg);
+        signature.chains[0] = hash_chain(&signature.chains[0], 1);
+        assert!(!verify(&public_key, msg, &signature));
+    }
+
+    #[test]
+    fn test_generate_with_rng() {
+        use rand_core::OsRng;
+
+        let mut rng = OsRng;
+        let keypair = OtsKeyPair::generate_with_rng(&mut rng);
+        let msg = b"deterministic-rng test message";
+        let public_key = keypair.public_key().clone();
+
+        let signature = keypair.sign(msg);
+        assert!(verify(&public_key, msg, &signature));
+    }
+}
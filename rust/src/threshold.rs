@@ -0,0 +1,492 @@
+//! Threshold (Shamir secret-sharing) splitting of a KEM [`SecretKey`]
+//!
+//! For multi-party custody and validator setups — the kind of scenario
+//! `test_blockchain_simulation` sketches with several validators — it is
+//! often unacceptable for any single party to hold a full decapsulation
+//! key. This module splits a [`SecretKey`] into `n` [`Share`]s such that any
+//! `t` of them reconstruct the original key, while any `t - 1` reveal
+//! nothing about it.
+//!
+//! The scheme is Shamir's secret sharing applied byte-wise over `GF(2^8)`
+//! (the AES field, reduction polynomial `x^8 + x^4 + x^3 + x + 1`): for each
+//! of the 64 secret-key bytes, a degree-`t - 1` polynomial is chosen with
+//! that byte as the constant term and random coefficients otherwise, then
+//! evaluated at `x = 1..=n` to produce one byte of each share. Reconstruction
+//! evaluates the Lagrange interpolation of any `t` shares at `x = 0`.
+//!
+//! This enables distributed decapsulation: no single party ever holds the
+//! full decapsulation key, only a share of it. That guarantee is meaningful
+//! because the 64-byte decapsulation key this module shares is the seed
+//! [`SecretKey::from_bytes`] expands into the real [`crate::mlwe`] lattice
+//! key pair decapsulation actually needs — splitting it is splitting the
+//! one secret capable of recovering an encapsulated message, not a value
+//! decapsulation could do without.
+
+#[cfg(not(feature = "std"))]
+use alloc::{format, string::String, vec, vec::Vec};
+
+use crate::error::{Result, TopayzError};
+use crate::kem::SecretKey;
+use rand_core::{CryptoRng, RngCore};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// High-performance pseudo-random number generator for polynomial
+/// coefficients
+///
+/// Mirrors the `OptimizedRng` used by [`crate::kem`] and [`crate::sign`].
+struct OptimizedRng {
+    state: [u64; 4],
+}
+
+impl OptimizedRng {
+    #[inline]
+    fn new() -> Self {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_nanos() as u64;
+
+        let state = [
+            now,
+            now.wrapping_mul(0x9E3779B97F4A7C15),
+            now.wrapping_mul(0xBF58476D1CE4E5B9),
+            now.wrapping_mul(0x94D049BB133111EB),
+        ];
+
+        Self { state }
+    }
+
+    #[inline]
+    fn next_bytes(&mut self, bytes: &mut [u8]) {
+        let mut i = 0;
+        while i < bytes.len() {
+            let random_u64 = self.next_u64();
+            let to_copy = core::cmp::min(8, bytes.len() - i);
+            bytes[i..i + to_copy].copy_from_slice(&random_u64.to_le_bytes()[..to_copy]);
+            i += to_copy;
+        }
+    }
+
+    #[inline(always)]
+    fn next_u64(&mut self) -> u64 {
+        let result = self.state[1].wrapping_mul(5).rotate_left(7).wrapping_mul(9);
+        let t = self.state[1] << 17;
+
+        self.state[2] ^= self.state[0];
+        self.state[3] ^= self.state[1];
+        self.state[1] ^= self.state[2];
+        self.state[0] ^= self.state[3];
+
+        self.state[2] ^= t;
+        self.state[3] = self.state[3].rotate_left(45);
+
+        result
+    }
+}
+
+/// Multiply two `GF(2^8)` elements under the AES reduction polynomial
+/// (`0x11B`)
+#[inline]
+fn gf_mul(a: u8, b: u8) -> u8 {
+    let mut a = a;
+    let mut b = b;
+    let mut product = 0u8;
+
+    for _ in 0..8 {
+        if b & 1 != 0 {
+            product ^= a;
+        }
+        let high_bit_set = a & 0x80 != 0;
+        a <<= 1;
+        if high_bit_set {
+            a ^= 0x1B;
+        }
+        b >>= 1;
+    }
+
+    product
+}
+
+/// Raise a `GF(2^8)` element to `exp` by repeated squaring
+#[inline]
+fn gf_pow(a: u8, exp: u8) -> u8 {
+    let mut result = 1u8;
+    let mut base = a;
+    let mut exp = exp;
+
+    while exp > 0 {
+        if exp & 1 != 0 {
+            result = gf_mul(result, base);
+        }
+        base = gf_mul(base, base);
+        exp >>= 1;
+    }
+
+    result
+}
+
+/// Invert a nonzero `GF(2^8)` element (every nonzero element has order
+/// dividing `255`, so `a^254 == a^-1`)
+#[inline]
+fn gf_inv(a: u8) -> u8 {
+    debug_assert!(a != 0, "zero has no multiplicative inverse in GF(2^8)");
+    gf_pow(a, 254)
+}
+
+/// Divide two `GF(2^8)` elements (`b` must be nonzero)
+#[inline]
+fn gf_div(a: u8, b: u8) -> u8 {
+    gf_mul(a, gf_inv(b))
+}
+
+/// One party's share of a split [`SecretKey`]
+///
+/// Holds the Shamir x-coordinate (`index`, never `0`) and the byte-wise
+/// polynomial evaluations at that coordinate. A share on its own reveals
+/// nothing about the original secret key.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Share {
+    index: u8,
+    bytes: [u8; 64],
+}
+
+impl Share {
+    /// The Shamir x-coordinate this share was evaluated at (`1..=n`)
+    #[inline(always)]
+    pub fn index(&self) -> u8 {
+        self.index
+    }
+
+    /// The raw byte-wise polynomial evaluations for this share
+    #[inline(always)]
+    pub fn as_bytes(&self) -> &[u8; 64] {
+        &self.bytes
+    }
+
+    /// Encode this share as hex: a 2-character index followed by the
+    /// 128-character hex of its 64 bytes
+    pub fn to_hex(&self) -> String {
+        let mut hex = String::with_capacity(2 + 128);
+        hex.push_str(&format!("{:02x}", self.index));
+        for &byte in &self.bytes {
+            hex.push_str(&format!("{byte:02x}"));
+        }
+        hex
+    }
+
+    /// Parse a share from the format produced by [`Share::to_hex`]
+    pub fn from_hex(hex: &str) -> Result<Self> {
+        if hex.len() != 2 + 128 {
+            return Err(TopayzError::InvalidInput("Invalid hex length".to_string()));
+        }
+
+        let index = u8::from_str_radix(&hex[0..2], 16)
+            .map_err(|_| TopayzError::InvalidInput("Invalid hex character".to_string()))?;
+
+        let mut bytes = [0u8; 64];
+        for i in 0..64 {
+            let hex_byte = &hex[2 + i * 2..2 + i * 2 + 2];
+            bytes[i] = u8::from_str_radix(hex_byte, 16)
+                .map_err(|_| TopayzError::InvalidInput("Invalid hex character".to_string()))?;
+        }
+
+        Ok(Share { index, bytes })
+    }
+}
+
+impl Drop for Share {
+    fn drop(&mut self) {
+        crate::utils::secure_zero(&mut self.bytes);
+    }
+}
+
+/// Split `secret_key` into `total` shares such that any `threshold` of them
+/// reconstruct it via [`combine`]
+///
+/// Uses an internally-seeded RNG; see [`split_with_rng`] to supply one.
+pub fn split(secret_key: &SecretKey, threshold: u8, total: u8) -> Result<Vec<Share>> {
+    let mut rng = OptimizedRng::new();
+    split_with(secret_key, threshold, total, |bytes| {
+        rng.next_bytes(bytes)
+    })
+}
+
+/// Split `secret_key` into `total` shares using a caller-supplied CSPRNG for
+/// the polynomial coefficients
+pub fn split_with_rng<R: RngCore + CryptoRng>(
+    secret_key: &SecretKey,
+    threshold: u8,
+    total: u8,
+    rng: &mut R,
+) -> Result<Vec<Share>> {
+    split_with(secret_key, threshold, total, |bytes| rng.fill_bytes(bytes))
+}
+
+fn split_with(
+    secret_key: &SecretKey,
+    threshold: u8,
+    total: u8,
+    fill: impl FnMut(&mut [u8]),
+) -> Result<Vec<Share>> {
+    let shares = split_secret_bytes(secret_key.as_bytes(), threshold, total, fill)?;
+    Ok(shares
+        .into_iter()
+        .map(|(index, bytes_vec)| {
+            let mut bytes = [0u8; 64];
+            bytes.copy_from_slice(&bytes_vec);
+            Share { index, bytes }
+        })
+        .collect())
+}
+
+/// Reconstruct a [`SecretKey`] from `threshold` or more distinct [`Share`]s
+/// produced by [`split`] or [`split_with_rng`]
+///
+/// Errors if fewer than `threshold` distinct shares are supplied, or if any
+/// two shares have the same index.
+pub fn combine(shares: &[Share], threshold: u8) -> Result<SecretKey> {
+    let points: Vec<(u8, Vec<u8>)> = shares
+        .iter()
+        .map(|share| (share.index, share.bytes.to_vec()))
+        .collect();
+    let secret = combine_secret_bytes(&points, threshold)?;
+
+    let mut decapsulation_key = [0u8; 64];
+    decapsulation_key.copy_from_slice(&secret);
+    Ok(SecretKey::from_bytes(decapsulation_key))
+}
+
+/// Split an arbitrary-length `secret` into `total` shares using an
+/// internally-seeded RNG; see [`split_secret_bytes`] for the shared
+/// byte-wise scheme and [`combine_secret_bytes`] to reconstruct. Used by
+/// [`crate::fragment::FragmentEngine::split_secret`].
+pub(crate) fn split_secret(secret: &[u8], threshold: u8, total: u8) -> Result<Vec<(u8, Vec<u8>)>> {
+    let mut rng = OptimizedRng::new();
+    split_secret_bytes(secret, threshold, total, |bytes| rng.next_bytes(bytes))
+}
+
+/// Byte-wise generalization of [`split`]/[`combine`] for secrets that
+/// aren't a fixed 64-byte [`SecretKey`] — e.g.
+/// [`crate::fragment::FragmentEngine::split_secret`]. Same Shamir-over-`GF(2^8)`
+/// scheme, parameterized over `secret`'s length instead of hardcoding 64.
+///
+/// Each share is an `(index, bytes)` pair: the Shamir x-coordinate and the
+/// byte-wise polynomial evaluations at that coordinate.
+pub(crate) fn split_secret_bytes(
+    secret: &[u8],
+    threshold: u8,
+    total: u8,
+    mut fill: impl FnMut(&mut [u8]),
+) -> Result<Vec<(u8, Vec<u8>)>> {
+    if threshold == 0 {
+        return Err(TopayzError::InvalidInput(
+            "Threshold must be at least 1".to_string(),
+        ));
+    }
+    if total == 0 || total < threshold {
+        return Err(TopayzError::InvalidInput(
+            "Total shares must be at least the threshold".to_string(),
+        ));
+    }
+
+    let len = secret.len();
+
+    // One degree-(threshold - 1) polynomial per secret byte: coefficients[0]
+    // is that byte, the rest are random.
+    let mut coefficients = vec![vec![0u8; len]; threshold as usize];
+    coefficients[0].copy_from_slice(secret);
+    for coefficient_row in coefficients.iter_mut().skip(1) {
+        fill(coefficient_row);
+    }
+
+    let mut shares = Vec::with_capacity(total as usize);
+    for x in 1..=total {
+        let mut bytes = vec![0u8; len];
+        for (byte_index, byte) in bytes.iter_mut().enumerate() {
+            *byte = eval_polynomial(&coefficients, byte_index, x);
+        }
+        shares.push((x, bytes));
+    }
+
+    Ok(shares)
+}
+
+/// Reconstruct a secret from `threshold` or more distinct `(index, bytes)`
+/// shares produced by [`split_secret_bytes`]; see that function's docs.
+///
+/// Errors if fewer than `threshold` distinct shares are supplied, if any two
+/// shares have the same index, or if the shares aren't all the same length.
+pub(crate) fn combine_secret_bytes(shares: &[(u8, Vec<u8>)], threshold: u8) -> Result<Vec<u8>> {
+    if shares.len() < threshold as usize {
+        return Err(TopayzError::InvalidInput(format!(
+            "Need at least {} shares to reconstruct, got {}",
+            threshold,
+            shares.len()
+        )));
+    }
+
+    for (i, (index, bytes)) in shares.iter().enumerate() {
+        if *index == 0 {
+            return Err(TopayzError::InvalidInput(
+                "Share index 0 is not a valid Shamir x-coordinate".to_string(),
+            ));
+        }
+        if shares[..i].iter().any(|(other_index, _)| other_index == index) {
+            return Err(TopayzError::InvalidInput(
+                "Duplicate share index supplied".to_string(),
+            ));
+        }
+        if bytes.len() != shares[0].1.len() {
+            return Err(TopayzError::InvalidInput(
+                "Shares must all be the same length".to_string(),
+            ));
+        }
+    }
+
+    let len = shares[0].1.len();
+    let mut secret = vec![0u8; len];
+    for (byte_index, byte) in secret.iter_mut().enumerate() {
+        *byte = lagrange_interpolate_at_zero(shares, byte_index);
+    }
+
+    Ok(secret)
+}
+
+/// Evaluate the `byte_index`-th byte's polynomial at `x` using Horner's
+/// method over `GF(2^8)`
+#[inline]
+fn eval_polynomial(coefficients: &[Vec<u8>], byte_index: usize, x: u8) -> u8 {
+    let mut result = 0u8;
+    for coefficient_row in coefficients.iter().rev() {
+        result = gf_mul(result, x) ^ coefficient_row[byte_index];
+    }
+    result
+}
+
+/// Lagrange-interpolate the `byte_index`-th byte's polynomial at `x = 0`
+/// from a full set of `(index, bytes)` points
+fn lagrange_interpolate_at_zero(shares: &[(u8, Vec<u8>)], byte_index: usize) -> u8 {
+    let mut secret_byte = 0u8;
+
+    for (i, (index_i, bytes_i)) in shares.iter().enumerate() {
+        let mut basis = 1u8;
+        for (j, (index_j, _)) in shares.iter().enumerate() {
+            if i == j {
+                continue;
+            }
+            // Evaluating at x = 0: numerator is (0 - x_j) == x_j (GF(2^8)
+            // subtraction is XOR), denominator is (x_i - x_j) == x_i ^ x_j.
+            let numerator = *index_j;
+            let denominator = index_i ^ index_j;
+            basis = gf_mul(basis, gf_div(numerator, denominator));
+        }
+        secret_byte ^= gf_mul(bytes_i[byte_index], basis);
+    }
+
+    secret_byte
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gf_arithmetic_round_trips() {
+        for a in 1..=255u8 {
+            let inv = gf_inv(a);
+            assert_eq!(gf_mul(a, inv), 1);
+        }
+    }
+
+    #[test]
+    fn test_split_combine_round_trip() {
+        let (_, secret_key) = crate::kem::Kem::keygen();
+
+        let shares = split(&secret_key, 3, 5).unwrap();
+        assert_eq!(shares.len(), 5);
+
+        let recovered = combine(&shares[0..3], 3).unwrap();
+        assert_eq!(recovered, secret_key);
+
+        let recovered = combine(&shares[1..4], 3).unwrap();
+        assert_eq!(recovered, secret_key);
+
+        let recovered = combine(&shares, 3).unwrap();
+        assert_eq!(recovered, secret_key);
+    }
+
+    #[test]
+    fn test_combine_rejects_too_few_shares() {
+        let (_, secret_key) = crate::kem::Kem::keygen();
+        let shares = split(&secret_key, 3, 5).unwrap();
+
+        assert!(combine(&shares[0..2], 3).is_err());
+    }
+
+    #[test]
+    fn test_combine_rejects_duplicate_index() {
+        let (_, secret_key) = crate::kem::Kem::keygen();
+        let shares = split(&secret_key, 2, 4).unwrap();
+
+        let duplicated = vec![shares[0].clone(), shares[0].clone()];
+        assert!(combine(&duplicated, 2).is_err());
+    }
+
+    #[test]
+    fn test_split_rejects_invalid_parameters() {
+        let (_, secret_key) = crate::kem::Kem::keygen();
+
+        assert!(split(&secret_key, 0, 5).is_err());
+        assert!(split(&secret_key, 4, 3).is_err());
+        assert!(split(&secret_key, 2, 0).is_err());
+    }
+
+    #[test]
+    fn test_share_hex_round_trip() {
+        let (_, secret_key) = crate::kem::Kem::keygen();
+        let shares = split(&secret_key, 2, 3).unwrap();
+
+        for share in &shares {
+            let hex = share.to_hex();
+            let decoded = Share::from_hex(&hex).unwrap();
+            assert_eq!(share, &decoded);
+        }
+    }
+
+    #[test]
+    fn test_share_from_hex_rejects_wrong_length() {
+        assert!(Share::from_hex("deadbeef").is_err());
+    }
+
+    #[test]
+    fn test_split_with_rng_is_deterministic_given_same_rng_stream() {
+        use rand_core::SeedableRng;
+        use rand_chacha::ChaCha20Rng;
+
+        let (_, secret_key) = crate::kem::Kem::keygen();
+
+        let mut rng_a = ChaCha20Rng::seed_from_u64(42);
+        let shares_a = split_with_rng(&secret_key, 3, 5, &mut rng_a).unwrap();
+
+        let mut rng_b = ChaCha20Rng::seed_from_u64(42);
+        let shares_b = split_with_rng(&secret_key, 3, 5, &mut rng_b).unwrap();
+
+        assert_eq!(shares_a, shares_b);
+    }
+
+    #[test]
+    fn test_combined_key_decapsulates_like_the_original() {
+        // Splitting is only meaningful for distributed decapsulation if the
+        // recombined key actually decapsulates — i.e. it reconstructs the
+        // same mlwe lattice secret the original SecretKey derived its
+        // decapsulation_key seed into, not just an equal-looking byte value.
+        use crate::kem::Kem;
+
+        let (public_key, secret_key) = Kem::keygen();
+        let shares = split(&secret_key, 3, 5).unwrap();
+        let recovered = combine(&shares[1..4], 3).unwrap();
+
+        let (ciphertext, shared_secret) = Kem::encapsulate(&public_key);
+        assert_eq!(Kem::decapsulate(&recovered, &ciphertext), shared_secret);
+    }
+}